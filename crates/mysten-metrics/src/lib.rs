@@ -209,6 +209,28 @@ pub fn get_server_timing() -> Option<Arc<Mutex<Timer>>> {
     SERVER_TIMING.try_with(|timer| timer.clone()).ok()
 }
 
+/// Spawn a task with the given name, which shows up in task dumps and tokio-console.
+/// Tasks are only named when built with `--cfg tokio_unstable`, and are spawned unnamed
+/// otherwise. Only intended for use by macros within this module.
+pub fn spawn_named<F>(name: &str, fut: F) -> tokio::task::JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    #[cfg(tokio_unstable)]
+    {
+        tokio::task::Builder::new()
+            .name(name)
+            .spawn(fut)
+            .expect("Spawning a task only fails outside of a runtime")
+    }
+    #[cfg(not(tokio_unstable))]
+    {
+        let _ = name;
+        tokio::task::spawn(fut)
+    }
+}
+
 /// Add a new entry to the ServerTiming header.
 /// If the caller is not currently in a ServerTiming context (created with `with_new_server_timing`),
 /// an error is logged.
@@ -280,6 +302,16 @@ macro_rules! forward_server_timing_and_spawn {
             tokio::task::spawn($fut)
         }
     };
+
+    ($fut: expr, $name: expr) => {
+        if let Some(timing) = $crate::get_server_timing() {
+            $crate::spawn_named($name, async move {
+                $crate::with_server_timing(timing, $fut).await
+            })
+        } else {
+            $crate::spawn_named($name, $fut)
+        }
+    };
 }
 
 #[macro_export]
@@ -289,6 +321,15 @@ macro_rules! spawn_monitored_task {
             tasks, $fut, "", INFO, false
         ))
     };
+
+    // The name must be a string literal. It replaces the line number in the task's label,
+    // and names the spawned task.
+    ($fut: expr, $name: expr) => {
+        $crate::forward_server_timing_and_spawn!(
+            $crate::monitored_future!(tasks, $fut, $name, INFO, false),
+            $name
+        )
+    };
 }
 
 #[macro_export]
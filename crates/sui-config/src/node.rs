@@ -168,6 +168,9 @@ pub struct NodeConfig {
     #[serde(default = "default_authority_overload_config")]
     pub authority_overload_config: AuthorityOverloadConfig,
 
+    #[serde(default)]
+    pub consensus_tx_status_cache_config: ConsensusTxStatusCacheConfig,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_with_range: Option<RunWithRange>,
 
@@ -1198,6 +1201,11 @@ pub struct AuthorityOverloadConfig {
     // is above the threshold.
     #[serde(default = "default_max_transaction_manager_per_object_queue_length")]
     pub max_transaction_manager_per_object_queue_length: usize,
+
+    // A warning with the missing input objects is logged once a transaction has been waiting
+    // for its input objects in the execution scheduler for longer than this.
+    #[serde(default = "default_input_objects_wait_warn_threshold")]
+    pub input_objects_wait_warn_threshold: Duration,
 }
 
 fn default_max_txn_age_in_queue() -> Duration {
@@ -1240,6 +1248,10 @@ fn default_max_transaction_manager_per_object_queue_length() -> usize {
     20
 }
 
+fn default_input_objects_wait_warn_threshold() -> Duration {
+    Duration::from_secs(5)
+}
+
 impl Default for AuthorityOverloadConfig {
     fn default() -> Self {
         Self {
@@ -1256,6 +1268,7 @@ impl Default for AuthorityOverloadConfig {
             max_transaction_manager_queue_length: default_max_transaction_manager_queue_length(),
             max_transaction_manager_per_object_queue_length:
                 default_max_transaction_manager_per_object_queue_length(),
+            input_objects_wait_warn_threshold: default_input_objects_wait_warn_threshold(),
         }
     }
 }
@@ -1264,6 +1277,65 @@ fn default_authority_overload_config() -> AuthorityOverloadConfig {
     AuthorityOverloadConfig::default()
 }
 
+/// The default number of consensus rounds to retain transaction status information before garbage collection.
+/// Used to expire positions from old rounds, as well as to check if a transaction is too far ahead of the last committed round.
+/// Assuming a max round rate of 15/sec, this allows status updates to be valid within a window of ~25-30 seconds.
+pub const CONSENSUS_STATUS_RETENTION_ROUNDS: u64 = 400;
+
+/// The default maximum number of transaction statuses held by the cache.
+/// Bounds memory usage when the last committed round stops advancing, e.g. under a stream of
+/// rejected transactions while commits stall.
+pub const CONSENSUS_STATUS_MAX_ENTRIES: usize = 1_000_000;
+
+/// The default time without a committed leader round after which consensus is considered stalled.
+pub const CONSENSUS_STALL_ALERT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Configuration of the cache of consensus transaction statuses, used by the validator to
+/// answer WaitForEffects requests on fastpath.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ConsensusTxStatusCacheConfig {
+    /// The number of consensus rounds to retain transaction status information before garbage collection.
+    /// Deployments with a slower consensus round rate can use a smaller value to keep the same time window.
+    #[serde(default = "default_consensus_status_retention_rounds")]
+    pub retention_rounds: u64,
+    /// The maximum number of transaction statuses to hold. When exceeded, statuses of the
+    /// oldest rounds are evicted before new positions are added.
+    #[serde(default = "default_consensus_status_max_entries")]
+    pub max_entries: usize,
+    /// Whether rejected positions are persisted to the epoch tables, so they can be restored
+    /// after a crash.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub persist_rejections: bool,
+    /// Consensus is reported as stalled when no leader round is committed for this long, after
+    /// the first one. Reported with a warning and the `consensus_stall_alerts` metric.
+    #[serde(default = "default_consensus_stall_alert_timeout")]
+    pub stall_alert_timeout: Duration,
+}
+
+fn default_consensus_status_retention_rounds() -> u64 {
+    CONSENSUS_STATUS_RETENTION_ROUNDS
+}
+
+fn default_consensus_status_max_entries() -> usize {
+    CONSENSUS_STATUS_MAX_ENTRIES
+}
+
+fn default_consensus_stall_alert_timeout() -> Duration {
+    CONSENSUS_STALL_ALERT_TIMEOUT
+}
+
+impl Default for ConsensusTxStatusCacheConfig {
+    fn default() -> Self {
+        Self {
+            retention_rounds: default_consensus_status_retention_rounds(),
+            max_entries: default_consensus_status_max_entries(),
+            persist_rejections: false,
+            stall_alert_timeout: default_consensus_stall_alert_timeout(),
+        }
+    }
+}
+
 fn default_traffic_controller_policy_config() -> Option<PolicyConfig> {
     Some(PolicyConfig::default_dos_protection_policy())
 }
@@ -0,0 +1,213 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use consensus_core::BlockRef;
+use criterion::*;
+use futures::future::join_all;
+use prometheus::Registry;
+use sui_core::authority::consensus_tx_status_cache::{
+    ConsensusRejectionReason, ConsensusTxStatus, ConsensusTxStatusCache,
+    ConsensusTxStatusCacheConfig, NotifyReadConsensusTxStatusResult,
+};
+use sui_core::epoch::epoch_metrics::EpochMetrics;
+use sui_core::wait_for_effects_request::ConsensusTxPosition;
+
+const POSITIONS_PER_ROUND: u16 = 100;
+
+fn make_cache(metrics: &Arc<EpochMetrics>) -> ConsensusTxStatusCache {
+    ConsensusTxStatusCache::new(0, ConsensusTxStatusCacheConfig::default(), metrics.clone())
+}
+
+fn make_position(round: u32, index: u16) -> ConsensusTxPosition {
+    ConsensusTxPosition::new(
+        BlockRef {
+            round,
+            ..BlockRef::MIN
+        },
+        index,
+    )
+}
+
+fn rejections(round: u32) -> impl Iterator<Item = (ConsensusTxPosition, ConsensusTxStatus)> {
+    (0..POSITIONS_PER_ROUND).map(move |index| {
+        (
+            make_position(round, index),
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+        )
+    })
+}
+
+fn reject_bench(c: &mut Criterion) {
+    let metrics = EpochMetrics::new(&Registry::new());
+    let num_rounds = 100;
+
+    let mut group = c.benchmark_group("consensus_tx_status_cache_reject");
+    group.throughput(Throughput::Elements(
+        num_rounds as u64 * POSITIONS_PER_ROUND as u64,
+    ));
+    group.bench_function("single_thread", |b| {
+        b.iter_batched(
+            || make_cache(&metrics),
+            |cache| {
+                for round in 1..=num_rounds {
+                    cache.set_transaction_statuses(rejections(round));
+                }
+                cache
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn concurrent_reject_and_commit_bench(c: &mut Criterion) {
+    let metrics = EpochMetrics::new(&Registry::new());
+    let num_threads: u32 = 8;
+    // One task advances the last committed round while the others reject positions.
+    let num_reject_tasks = num_threads - 1;
+    let rounds_per_task: u32 = 50;
+    let num_rounds = num_reject_tasks * rounds_per_task;
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(num_threads as usize)
+        .build()
+        .unwrap();
+
+    let mut group = c.benchmark_group("consensus_tx_status_cache_concurrent");
+    group.throughput(Throughput::Elements(
+        num_rounds as u64 * POSITIONS_PER_ROUND as u64,
+    ));
+    group.sample_size(20);
+    group.bench_function(format!("num_threads={num_threads}"), |b| {
+        b.iter_batched(
+            || Arc::new(make_cache(&metrics)),
+            |cache| {
+                let mut handles: Vec<_> = (0..num_reject_tasks)
+                    .map(|task| {
+                        let cache = cache.clone();
+                        runtime.spawn(async move {
+                            for i in 0..rounds_per_task {
+                                let round = i * num_reject_tasks + task + 1;
+                                cache.set_transaction_statuses(rejections(round));
+                            }
+                        })
+                    })
+                    .collect();
+                handles.push({
+                    let cache = cache.clone();
+                    runtime.spawn(async move {
+                        for round in 1..=num_rounds {
+                            cache.update_last_committed_leader_round(round as u64);
+                        }
+                    })
+                });
+                runtime.block_on(async move {
+                    join_all(handles).await;
+                });
+                cache
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn concurrent_writers_bench(c: &mut Criterion) {
+    let metrics = EpochMetrics::new(&Registry::new());
+    let num_writers: u32 = 16;
+    let rounds_per_writer: u32 = 10;
+    let num_rounds = num_writers * rounds_per_writer;
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(num_writers as usize)
+        .build()
+        .unwrap();
+
+    // Writers set statuses one at a time instead of in batches, so that each update contends
+    // for the cache lock.
+    let mut group = c.benchmark_group("consensus_tx_status_cache_contention");
+    group.throughput(Throughput::Elements(
+        num_rounds as u64 * POSITIONS_PER_ROUND as u64,
+    ));
+    group.sample_size(20);
+    group.bench_function(format!("num_writers={num_writers}"), |b| {
+        b.iter_batched(
+            || Arc::new(make_cache(&metrics)),
+            |cache| {
+                let handles: Vec<_> = (0..num_writers)
+                    .map(|writer| {
+                        let cache = cache.clone();
+                        runtime.spawn(async move {
+                            for i in 0..rounds_per_writer {
+                                let round = i * num_writers + writer + 1;
+                                for (position, status) in rejections(round) {
+                                    cache.set_transaction_status(position, status);
+                                }
+                            }
+                        })
+                    })
+                    .collect();
+                runtime.block_on(async move {
+                    join_all(handles).await;
+                });
+                cache
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn rejection_wakeup_bench(c: &mut Criterion) {
+    let metrics = EpochMetrics::new(&Registry::new());
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let mut group = c.benchmark_group("consensus_tx_status_cache_wakeup");
+    group.bench_function("rejection", |b| {
+        b.iter_custom(|iters| {
+            let cache = Arc::new(make_cache(&metrics));
+            runtime.block_on(async move {
+                let mut total = Duration::ZERO;
+                for i in 0..iters {
+                    let position = make_position((i + 1) as u32, 0);
+                    let waiter = {
+                        let cache = cache.clone();
+                        tokio::spawn(async move {
+                            cache.notify_read_transaction_status(position, None).await
+                        })
+                    };
+                    // Let the waiter register for notifications before the rejection.
+                    tokio::task::yield_now().await;
+
+                    let start = Instant::now();
+                    cache.set_transaction_statuses(std::iter::once((
+                        position,
+                        ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+                    )));
+                    let result = waiter.await.unwrap();
+                    total += start.elapsed();
+                    assert!(matches!(
+                        result,
+                        NotifyReadConsensusTxStatusResult::Status(ConsensusTxStatus::Rejected(_))
+                    ));
+                }
+                total
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    reject_bench,
+    concurrent_reject_and_commit_bench,
+    concurrent_writers_bench,
+    rejection_wakeup_bench
+);
+criterion_main!(benches);
@@ -0,0 +1,60 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use criterion::*;
+
+use sui_core::wait_for_effects_request::{
+    ExecutedData, WaitForEffectsResponse, WaitForEffectsResponseCache,
+    WaitForEffectsResponseCacheKey, WAIT_FOR_EFFECTS_RESPONSE_CACHE_SIZE_BYTES,
+};
+use sui_types::digests::{TransactionDigest, TransactionEffectsDigest};
+use sui_types::effects::TransactionEffects;
+use sui_types::messages_grpc::RawWaitForEffectsResponse;
+use sui_types::object::Object;
+
+fn make_response(num_objects: usize) -> WaitForEffectsResponse {
+    let objects: Vec<_> = (0..num_objects)
+        .map(|_| Object::new_gas_for_testing())
+        .collect();
+    WaitForEffectsResponse::Executed {
+        effects_digest: TransactionEffectsDigest::random(),
+        details: Some(Box::new(ExecutedData {
+            effects: TransactionEffects::default(),
+            events: None,
+            input_objects: objects.clone(),
+            output_objects: objects,
+            auxiliary_objects: vec![],
+            signature: None,
+        })),
+        gas_cost_summary: None,
+    }
+}
+
+fn wait_for_effects_response_cache_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wait_for_effects_response");
+    for num_objects in [1, 10, 100] {
+        let response = make_response(num_objects);
+        let key = WaitForEffectsResponseCacheKey {
+            epoch: 0,
+            transaction_digest: TransactionDigest::random(),
+            include_details: true,
+            include_auxiliary_data: false,
+            include_gas_summary: false,
+        };
+        let cache = WaitForEffectsResponseCache::new(WAIT_FOR_EFFECTS_RESPONSE_CACHE_SIZE_BYTES);
+        cache.insert(key, response.clone().try_into().unwrap());
+
+        group.bench_with_input(
+            BenchmarkId::new("encode", num_objects),
+            &response,
+            |b, response| b.iter(|| RawWaitForEffectsResponse::try_from(response.clone()).unwrap()),
+        );
+        group.bench_with_input(BenchmarkId::new("cached", num_objects), &key, |b, key| {
+            b.iter(|| cache.get(key).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, wait_for_effects_response_cache_bench);
+criterion_main!(benches);
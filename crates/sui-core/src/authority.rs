@@ -6,6 +6,7 @@ use crate::congestion_tracker::CongestionTracker;
 use crate::consensus_adapter::ConsensusOverloadChecker;
 use crate::execution_cache::ExecutionCacheTraitPointers;
 use crate::execution_cache::TransactionCacheRead;
+use crate::execution_scheduler::ready_certificates_channel;
 use crate::execution_scheduler::ExecutionSchedulerAPI;
 use crate::execution_scheduler::ExecutionSchedulerWrapper;
 use crate::execution_scheduler::SchedulingSource;
@@ -67,7 +68,6 @@ use sui_types::messages_consensus::{AuthorityCapabilitiesV1, AuthorityCapabiliti
 use sui_types::object::bounded_visitor::BoundedVisitor;
 use sui_types::transaction_executor::SimulateTransactionResult;
 use tap::TapFallible;
-use tokio::sync::mpsc::unbounded_channel;
 use tokio::sync::RwLock;
 use tokio::sync::{mpsc, oneshot};
 use tokio::task::JoinHandle;
@@ -278,6 +278,10 @@ pub struct AuthorityMetrics {
     pub(crate) transaction_manager_package_cache_evictions: IntCounter,
     pub(crate) transaction_manager_transaction_queue_age_s: Histogram,
 
+    pub(crate) execution_scheduler_schedule_latency_s: Histogram,
+    pub(crate) execution_scheduler_num_scheduling_tasks: IntGauge,
+    pub(crate) execution_scheduler_receiving_object_waits: IntCounter,
+
     pub(crate) execution_driver_executed_transactions: IntCounter,
     pub(crate) execution_driver_dispatch_queue: IntGauge,
     pub(crate) execution_queueing_delay_s: Histogram,
@@ -613,6 +617,25 @@ impl AuthorityMetrics {
                 registry,
             )
             .unwrap(),
+            execution_scheduler_schedule_latency_s: register_histogram_with_registry!(
+                "execution_scheduler_schedule_latency_s",
+                "Time from enqueueing a transaction in ExecutionScheduler until it is sent for execution",
+                LOW_LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+            execution_scheduler_num_scheduling_tasks: register_int_gauge_with_registry!(
+                "execution_scheduler_num_scheduling_tasks",
+                "Current number of transactions being scheduled by ExecutionScheduler",
+                registry,
+            )
+            .unwrap(),
+            execution_scheduler_receiving_object_waits: register_int_counter_with_registry!(
+                "execution_scheduler_receiving_object_waits",
+                "Number of transactions in ExecutionScheduler that had to wait for receiving objects",
+                registry,
+            )
+            .unwrap(),
             transaction_overload_sources: register_int_counter_vec_with_registry!(
                 "transaction_overload_sources",
                 "Number of times each source indicates transaction overload.",
@@ -2977,7 +3000,6 @@ impl AuthorityState {
         }
     }
 
-    #[allow(clippy::disallowed_methods)] // allow unbounded_channel()
     pub async fn new(
         name: AuthorityName,
         secret: StableSyncAuthoritySigner,
@@ -3000,7 +3022,7 @@ impl AuthorityState {
         Self::check_protocol_version(supported_protocol_versions, epoch_store.protocol_version());
 
         let metrics = Arc::new(AuthorityMetrics::new(prometheus_registry));
-        let (tx_ready_certificates, rx_ready_certificates) = unbounded_channel();
+        let (tx_ready_certificates, rx_ready_certificates) = ready_certificates_channel();
         let execution_scheduler = Arc::new(ExecutionSchedulerWrapper::new(
             execution_cache_trait_pointers.object_cache_reader.clone(),
             execution_cache_trait_pointers
@@ -3008,6 +3030,7 @@ impl AuthorityState {
                 .clone(),
             tx_ready_certificates,
             &epoch_store,
+            &config.authority_overload_config,
             metrics.clone(),
         ));
         let (tx_execution_shutdown, rx_execution_shutdown) = oneshot::channel();
@@ -3146,6 +3169,35 @@ impl AuthorityState {
         &self.execution_scheduler
     }
 
+    /// Drops the transactions that are waiting for input objects in the execution scheduler.
+    /// Called when the node shuts down, so that their scheduling tasks do not outlive it.
+    pub fn abort_execution_scheduler(&self) {
+        match self.execution_scheduler.as_ref() {
+            ExecutionSchedulerWrapper::ExecutionScheduler(scheduler) => scheduler.abort(),
+            ExecutionSchedulerWrapper::TransactionManager(_) => {}
+        }
+    }
+
+    /// Stops the execution scheduler from accepting new transactions, and waits up to `timeout`
+    /// for the transactions it is already scheduling to be sent for execution.
+    /// Called when the node shuts down, before `abort_execution_scheduler()`.
+    pub async fn shutdown_execution_scheduler(&self, timeout: Duration) {
+        match self.execution_scheduler.as_ref() {
+            ExecutionSchedulerWrapper::ExecutionScheduler(scheduler) => {
+                if tokio::time::timeout(timeout, scheduler.shutdown())
+                    .await
+                    .is_err()
+                {
+                    warn!(
+                        pending_count = scheduler.pending_count(),
+                        "Timed out draining ExecutionScheduler after {:?}", timeout
+                    );
+                }
+            }
+            ExecutionSchedulerWrapper::TransactionManager(_) => {}
+        }
+    }
+
     /// Adds transactions / certificates to transaction manager for ordered execution.
     /// TODO: Cleanup this function.
     pub fn enqueue_transactions_for_execution(
@@ -85,7 +85,10 @@ use typed_store::DBMapUtils;
 use typed_store::Map;
 
 use super::authority_store_tables::ENV_VAR_LOCKS_BLOCK_CACHE_SIZE;
-use super::consensus_tx_status_cache::{ConsensusTxStatus, ConsensusTxStatusCache};
+use super::consensus_tx_status_cache::{
+    ConsensusRejectionReason, ConsensusTxStatus, ConsensusTxStatusCache,
+    ConsensusTxStatusCacheConfig,
+};
 use super::epoch_start_configuration::EpochStartConfigTrait;
 use super::execution_time_estimator::{ConsensusObservations, ExecutionTimeEstimator};
 use super::shared_object_congestion_tracker::{
@@ -412,6 +415,8 @@ pub struct AuthorityPerEpochStore {
     end_of_epoch_execution_time_observations: OnceCell<StoredExecutionTimeObservations>,
 
     pub(crate) consensus_tx_status_cache: Option<ConsensusTxStatusCache>,
+    /// Configuration of the consensus tx status cache, kept to create the cache of the next epoch.
+    consensus_tx_status_cache_config: ConsensusTxStatusCacheConfig,
 }
 
 /// AuthorityEpochTables contains tables that contain data that is only valid within an epoch.
@@ -553,6 +558,10 @@ pub struct AuthorityEpochTables {
     /// Execution time observations for congestion control.
     pub(crate) execution_time_observations:
         DBMap<(u64, AuthorityIndex), Vec<(ExecutionTimeObservationKey, Duration)>>,
+
+    /// Transactions rejected by consensus, persisted for crash recovery of the consensus
+    /// transaction status cache. Only written when rejection persistence is enabled.
+    pub(crate) consensus_tx_rejections: DBMap<ConsensusTxPosition, ConsensusRejectionReason>,
 }
 
 fn signed_transactions_table_default_config() -> DBOptions {
@@ -824,6 +833,15 @@ impl AuthorityEpochTables {
                 "execution_time_observations".to_string(),
                 ThConfig::new(8 + 4, MUTEXES, uniform_key),
             ),
+            (
+                "consensus_tx_rejections".to_string(),
+                ThConfig::new_with_config_indexing(
+                    KeyIndexing::Hash,
+                    MUTEXES,
+                    uniform_key,
+                    KeySpaceConfig::default(),
+                ),
+            ),
         ];
         Self::open_tables_read_write(
             Self::path(epoch, parent_path),
@@ -949,6 +967,7 @@ impl AuthorityPerEpochStore {
         expensive_safety_check_config: &ExpensiveSafetyCheckConfig,
         chain: (ChainIdentifier, Chain),
         highest_executed_checkpoint: CheckpointSequenceNumber,
+        consensus_tx_status_cache_config: &ConsensusTxStatusCacheConfig,
     ) -> SuiResult<Arc<Self>> {
         let current_time = Instant::now();
         let epoch_id = committee.epoch;
@@ -1099,7 +1118,20 @@ impl AuthorityPerEpochStore {
             };
 
         let consensus_tx_status_cache = if protocol_config.mysticeti_fastpath() {
-            Some(ConsensusTxStatusCache::new())
+            let last_committed_leader_round = tables
+                .get_last_consensus_stats()?
+                .map(|stats| stats.index.last_committed_round);
+            Some(
+                ConsensusTxStatusCache::new(
+                    epoch_id,
+                    consensus_tx_status_cache_config.clone(),
+                    metrics.clone(),
+                )
+                .with_rejections_table(
+                    &tables.consensus_tx_rejections,
+                    last_committed_leader_round,
+                )?,
+            )
         } else {
             None
         };
@@ -1144,6 +1176,7 @@ impl AuthorityPerEpochStore {
             tx_object_debts: OnceCell::new(),
             end_of_epoch_execution_time_observations: OnceCell::new(),
             consensus_tx_status_cache,
+            consensus_tx_status_cache_config: consensus_tx_status_cache_config.clone(),
         });
 
         s.update_buffer_stake_metric();
@@ -1292,6 +1325,7 @@ impl AuthorityPerEpochStore {
             expensive_safety_check_config,
             self.chain,
             previous_epoch_last_checkpoint,
+            &self.consensus_tx_status_cache_config,
         )
     }
 
@@ -2828,6 +2862,10 @@ impl AuthorityPerEpochStore {
         self.epoch_alive_notify
             .notify()
             .expect("epoch_terminated called twice on same epoch store");
+        // Release pending reads of consensus transaction statuses.
+        if let Some(cache) = self.consensus_tx_status_cache.as_ref() {
+            cache.clear_all();
+        }
         // This `write` acts as a barrier - it waits for futures executing in
         // `within_alive_epoch` to terminate before we can continue here
         debug!("Epoch terminated - waiting for pending tasks to complete");
@@ -3072,6 +3110,9 @@ impl AuthorityPerEpochStore {
         }
 
         let mut output = ConsensusCommitOutput::new(consensus_commit_info.round);
+        if let Some(cache) = self.consensus_tx_status_cache.as_ref() {
+            output.record_consensus_tx_rejections(cache.take_pending_rejection_writes());
+        }
 
         // Load transactions deferred from previous commits.
         let deferred_txs: Vec<(DeferralKey, Vec<VerifiedSequencedConsensusTransaction>)> = self
@@ -4683,6 +4724,7 @@ impl AuthorityPerEpochStore {
         );
     }
 
+    #[cfg(test)]
     pub(crate) fn set_consensus_tx_status(
         &self,
         position: ConsensusTxPosition,
@@ -4693,6 +4735,23 @@ impl AuthorityPerEpochStore {
         }
     }
 
+    pub(crate) fn set_consensus_tx_statuses(
+        &self,
+        updates: Vec<(ConsensusTxPosition, ConsensusTxStatus)>,
+    ) {
+        if let Some(cache) = self.consensus_tx_status_cache.as_ref() {
+            cache.set_transaction_statuses(updates);
+        }
+    }
+
+    /// Only used by admin API
+    pub fn get_consensus_rejected_tx_positions(&self) -> Vec<ConsensusTxPosition> {
+        self.consensus_tx_status_cache
+            .as_ref()
+            .map(|cache| cache.snapshot())
+            .unwrap_or_default()
+    }
+
     /// Only used by admin API
     pub async fn get_estimated_tx_cost(&self, tx: &TransactionData) -> Option<u64> {
         self.execution_time_estimator
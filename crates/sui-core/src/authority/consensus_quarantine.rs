@@ -4,10 +4,12 @@
 use crate::authority::authority_per_epoch_store::{
     AuthorityEpochTables, EncG, ExecutionIndicesWithStats, PkG,
 };
+use crate::authority::consensus_tx_status_cache::ConsensusRejectionReason;
 use crate::authority::transaction_deferral::DeferralKey;
 use crate::checkpoints::BuilderCheckpointSummary;
 use crate::consensus_handler::SequencedConsensusTransactionKind;
 use crate::epoch::randomness::SINGLETON_KEY;
+use crate::wait_for_effects_request::ConsensusTxPosition;
 use dashmap::DashMap;
 use fastcrypto_tbls::{dkg_v1, nodes::PartyId};
 use fastcrypto_zkp::bn254::zk_login::{JwkId, JWK};
@@ -95,6 +97,9 @@ pub(crate) struct ConsensusCommitOutput {
         u64, /* generation */
         Vec<(ExecutionTimeObservationKey, Duration)>,
     )>,
+
+    // consensus tx status state: rejections to write, or to delete when None
+    consensus_tx_rejections: BTreeMap<ConsensusTxPosition, Option<ConsensusRejectionReason>>,
 }
 
 impl ConsensusCommitOutput {
@@ -156,6 +161,13 @@ impl ConsensusCommitOutput {
             .push((source, generation, estimates));
     }
 
+    pub(crate) fn record_consensus_tx_rejections(
+        &mut self,
+        rejections: BTreeMap<ConsensusTxPosition, Option<ConsensusRejectionReason>>,
+    ) {
+        self.consensus_tx_rejections.extend(rejections);
+    }
+
     pub(crate) fn record_consensus_commit_stats(&mut self, stats: ExecutionIndicesWithStats) {
         self.consensus_commit_stats = Some(stats);
     }
@@ -352,6 +364,20 @@ impl ConsensusCommitOutput {
                 .map(|(authority, generation, estimates)| ((generation, authority), estimates)),
         )?;
 
+        batch.delete_batch(
+            &tables.consensus_tx_rejections,
+            self.consensus_tx_rejections
+                .iter()
+                .filter(|(_, reason)| reason.is_none())
+                .map(|(position, _)| *position),
+        )?;
+        batch.insert_batch(
+            &tables.consensus_tx_rejections,
+            self.consensus_tx_rejections
+                .into_iter()
+                .filter_map(|(position, reason)| reason.map(|reason| (position, reason))),
+        )?;
+
         Ok(())
     }
 }
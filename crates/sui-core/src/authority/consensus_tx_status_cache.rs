@@ -1,46 +1,141 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use parking_lot::RwLock;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use parking_lot::{RwLock, RwLockReadGuard};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use sui_types::committee::EpochId;
 use sui_types::error::{SuiError, SuiResult};
-use tokio::sync::watch;
-use tracing::debug;
+use tokio::sync::{broadcast, watch};
+use tracing::{debug, info, instrument, warn};
+use typed_store::rocks::DBMap;
+use typed_store::Map;
 
-use mysten_common::sync::notify_read::NotifyRead;
+use consensus_config::AuthorityIndex;
+use mysten_common::debug_fatal;
+use mysten_common::sync::{notify_once::NotifyOnce, notify_read::NotifyRead};
+pub use sui_config::node::{
+    ConsensusTxStatusCacheConfig, CONSENSUS_STALL_ALERT_TIMEOUT, CONSENSUS_STATUS_MAX_ENTRIES,
+    CONSENSUS_STATUS_RETENTION_ROUNDS,
+};
 
-use crate::wait_for_effects_request::ConsensusTxPosition;
+use crate::epoch::epoch_metrics::EpochMetrics;
+use crate::wait_for_effects_request::{ConsensusRejectionCode, ConsensusTxPosition};
 
-/// The number of consensus rounds to retain transaction status information before garbage collection.
-/// Used to expire positions from old rounds, as well as to check if a transaction is too far ahead of the last committed round.
-/// Assuming a max round rate of 15/sec, this allows status updates to be valid within a window of ~25-30 seconds.
-pub(crate) const CONSENSUS_STATUS_RETENTION_ROUNDS: u64 = 400;
+/// Number of rejections buffered for each subscriber. Subscribers that fall further behind
+/// miss the oldest rejections.
+pub const CONSENSUS_REJECTION_SUBSCRIPTION_CAPACITY: usize = 10_000;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub(crate) enum ConsensusTxStatus {
+pub enum ConsensusTxStatus {
     // Transaction is voted to accept by a quorum of validators on fastpath.
     FastpathCertified,
     // Transaction is rejected, either by a quorum of validators or indirectly post-commit.
-    Rejected,
+    Rejected(ConsensusRejectionReason),
     // Transaction is finalized post commit.
     Finalized,
 }
 
+impl ConsensusTxStatus {
+    fn metric_label(&self) -> &'static str {
+        match self {
+            ConsensusTxStatus::FastpathCertified => "fastpath_certified",
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath) => "rejected_fastpath",
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit) => {
+                "rejected_post_commit"
+            }
+            ConsensusTxStatus::Finalized => "finalized",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsensusRejectionReason {
+    // Transaction is rejected by a quorum of validators when its block is certified on fastpath.
+    FastPath,
+    // Transaction is rejected when the consensus commit containing it is processed.
+    PostCommit,
+}
+
+impl ConsensusRejectionReason {
+    fn metric_label(&self) -> &'static str {
+        match self {
+            ConsensusRejectionReason::FastPath => "fastpath",
+            ConsensusRejectionReason::PostCommit => "post_commit",
+        }
+    }
+}
+
+impl From<ConsensusRejectionReason> for ConsensusRejectionCode {
+    fn from(reason: ConsensusRejectionReason) -> Self {
+        match reason {
+            ConsensusRejectionReason::FastPath => ConsensusRejectionCode::FastPath,
+            ConsensusRejectionReason::PostCommit => ConsensusRejectionCode::PostCommit,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
-pub(crate) enum NotifyReadConsensusTxStatusResult {
+pub enum NotifyReadConsensusTxStatusResult {
     // The consensus position to be read has been updated with a new status.
     Status(ConsensusTxStatus),
-    // The consensus position to be read has expired.
+    // The consensus position to be read has expired, or was evicted from the full cache.
     // Provided with the last committed round that was used to check for expiration.
     Expired(u64),
+    // The cache has been cleared at the end of the epoch.
+    Cleared,
 }
 
-pub(crate) struct ConsensusTxStatusCache {
+pub struct ConsensusTxStatusCache {
+    /// The epoch of the tracked positions. Consensus rounds restart from 0 in every epoch,
+    /// so positions are only comparable with rounds of the same epoch.
+    epoch: EpochId,
+    /// The number of consensus rounds to retain transaction status information.
+    retention_rounds: u64,
+    /// The maximum number of transaction statuses to hold.
+    max_entries: usize,
+    /// Whether changes to rejected positions are buffered to be persisted.
+    persist_rejections: bool,
     inner: RwLock<Inner>,
-    status_notify_read: NotifyRead<ConsensusTxPosition, ConsensusTxStatus>,
+    /// Notified with the new status of a position, or when the position is evicted.
+    status_notify_read: NotifyRead<ConsensusTxPosition, NotifyReadConsensusTxStatusResult>,
     /// Watch channel for last committed leader round updates
     last_committed_leader_round_tx: watch::Sender<Option<u64>>,
     last_committed_leader_round_rx: watch::Receiver<Option<u64>>,
+    /// Notified when the cache is cleared, to release all pending reads.
+    cleared_notify: NotifyOnce,
+    /// Broadcasts every newly observed rejection to subscribers.
+    rejections_tx: broadcast::Sender<(ConsensusTxPosition, ConsensusRejectionReason)>,
+    metrics: Arc<EpochMetrics>,
+}
+
+// Does not block on the inner lock, so the cache can be formatted from any context,
+// including while the lock is held.
+impl std::fmt::Debug for ConsensusTxStatusCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("ConsensusTxStatusCache");
+        debug
+            .field("epoch", &self.epoch)
+            .field("retention_rounds", &self.retention_rounds);
+        match self.inner.try_read() {
+            Some(inner) => {
+                let rejected_count = inner
+                    .transaction_status
+                    .values()
+                    .filter(|status| matches!(status, ConsensusTxStatus::Rejected(_)))
+                    .count();
+                debug
+                    .field("status_count", &inner.transaction_status.len())
+                    .field("rejected_count", &rejected_count)
+            }
+            None => debug
+                .field("status_count", &format_args!("<locked>"))
+                .field("rejected_count", &format_args!("<locked>")),
+        };
+        debug.finish_non_exhaustive()
+    }
 }
 
 #[derive(Default)]
@@ -48,74 +143,376 @@ struct Inner {
     /// A map of transaction position to its status from consensus.
     transaction_status: HashMap<ConsensusTxPosition, ConsensusTxStatus>,
     /// A map of consensus round to all transactions that were updated in that round.
-    round_lookup_map: BTreeMap<u64, HashSet<ConsensusTxPosition>>,
+    round_lookup_map: BTreeMap<u64, BTreeSet<ConsensusTxPosition>>,
+    /// The number of positions rejected so far, by the authority of the proposing block.
+    rejections_by_authority: HashMap<AuthorityIndex, u64>,
+    /// Changes to persisted rejections not yet taken to be written with a consensus commit,
+    /// if persistence is enabled. A reason is written, and None deletes the position.
+    pending_rejection_writes: BTreeMap<ConsensusTxPosition, Option<ConsensusRejectionReason>>,
+}
+
+impl Inner {
+    /// Returns the rejected positions of a round, in position order.
+    fn rejected_in_round(&self, round: u64) -> impl Iterator<Item = &ConsensusTxPosition> {
+        self.round_lookup_map
+            .get(&round)
+            .into_iter()
+            .flatten()
+            .filter(|position| {
+                matches!(
+                    self.transaction_status.get(position),
+                    Some(ConsensusTxStatus::Rejected(_))
+                )
+            })
+    }
+
+    /// Removes the status of a position from the oldest tracked round.
+    fn evict_oldest(&mut self) -> Option<(ConsensusTxPosition, ConsensusTxStatus)> {
+        let mut oldest_round = self.round_lookup_map.first_entry()?;
+        let position = oldest_round
+            .get_mut()
+            .pop_first()
+            .expect("Round lookup map should not contain empty rounds");
+        if oldest_round.get().is_empty() {
+            oldest_round.remove();
+        }
+        let status = self
+            .transaction_status
+            .remove(&position)
+            .expect("Tracked position should have a status");
+        Some((position, status))
+    }
+}
+
+/// A read-only view of the transaction statuses in a ConsensusTxStatusCache, for tests and
+/// admin tooling. The view holds the read lock of the cache, so status updates and round
+/// updates block until it is dropped. It should not be held across awaits.
+pub struct ConsensusTxStatusCacheView<'a> {
+    inner: RwLockReadGuard<'a, Inner>,
+}
+
+impl ConsensusTxStatusCacheView<'_> {
+    /// Returns true if the position has a known status.
+    pub fn contains(&self, position: &ConsensusTxPosition) -> bool {
+        self.inner.transaction_status.contains_key(position)
+    }
+
+    pub fn get(&self, position: &ConsensusTxPosition) -> Option<ConsensusTxStatus> {
+        self.inner.transaction_status.get(position).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.transaction_status.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.transaction_status.is_empty()
+    }
+
+    /// Iterates over all positions with a known status, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&ConsensusTxPosition, &ConsensusTxStatus)> {
+        self.inner.transaction_status.iter()
+    }
 }
 
 impl ConsensusTxStatusCache {
-    pub fn new() -> Self {
+    pub fn new(
+        epoch: EpochId,
+        config: ConsensusTxStatusCacheConfig,
+        metrics: Arc<EpochMetrics>,
+    ) -> Self {
         let (last_committed_leader_round_tx, last_committed_leader_round_rx) = watch::channel(None);
+        // Caches created outside of a runtime, e.g. in tools, go without the alarm.
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(Self::monitor_stalls(
+                epoch,
+                last_committed_leader_round_rx.clone(),
+                config.stall_alert_timeout,
+                metrics.clone(),
+            ));
+        }
         Self {
+            epoch,
+            retention_rounds: config.retention_rounds,
+            max_entries: config.max_entries,
+            persist_rejections: config.persist_rejections,
             inner: Default::default(),
             status_notify_read: Default::default(),
             last_committed_leader_round_tx,
             last_committed_leader_round_rx,
+            cleared_notify: NotifyOnce::new(),
+            rejections_tx: broadcast::channel(CONSENSUS_REJECTION_SUBSCRIPTION_CAPACITY).0,
+            metrics,
+        }
+    }
+
+    /// Reports consensus as stalled every `stall_alert_timeout` without a new committed leader
+    /// round. Returns once the cache is dropped.
+    async fn monitor_stalls(
+        epoch: EpochId,
+        mut last_committed_leader_round_rx: watch::Receiver<Option<u64>>,
+        stall_alert_timeout: Duration,
+        metrics: Arc<EpochMetrics>,
+    ) {
+        loop {
+            match tokio::time::timeout(
+                stall_alert_timeout,
+                last_committed_leader_round_rx.changed(),
+            )
+            .await
+            {
+                Ok(Ok(())) => {}
+                Ok(Err(_)) => return,
+                Err(_) => {
+                    // Nothing is expected before consensus commits its first round.
+                    let Some(last_committed_leader_round) =
+                        *last_committed_leader_round_rx.borrow()
+                    else {
+                        continue;
+                    };
+                    warn!(
+                        epoch,
+                        last_committed_leader_round,
+                        ?stall_alert_timeout,
+                        "No leader round committed within the stall alert timeout, \
+                         consensus may be stalled",
+                    );
+                    metrics.consensus_stall_alerts.inc();
+                }
+            }
         }
     }
 
+    /// Restores the rejections persisted to `table` before a restart. Positions expired against
+    /// `last_committed_leader_round` are deleted with the next consensus commit instead of being
+    /// restored. Does nothing unless `persist_rejections` is configured.
+    pub fn with_rejections_table(
+        self,
+        table: &DBMap<ConsensusTxPosition, ConsensusRejectionReason>,
+        last_committed_leader_round: Option<u64>,
+    ) -> SuiResult<Self> {
+        if !self.persist_rejections {
+            return Ok(self);
+        }
+        {
+            let mut inner = self.inner.write();
+            let mut num_expired = 0;
+            for result in table.safe_iter() {
+                let (transaction_position, reason) = result?;
+                if let Some(last_committed_leader_round) = last_committed_leader_round {
+                    if transaction_position.block.round as u64 + self.retention_rounds
+                        < last_committed_leader_round
+                    {
+                        inner
+                            .pending_rejection_writes
+                            .insert(transaction_position, None);
+                        num_expired += 1;
+                        continue;
+                    }
+                }
+                inner
+                    .round_lookup_map
+                    .entry(transaction_position.block.round as u64)
+                    .or_default()
+                    .insert(transaction_position);
+                inner
+                    .transaction_status
+                    .insert(transaction_position, ConsensusTxStatus::Rejected(reason));
+            }
+            info!(
+                epoch = self.epoch,
+                num_restored = inner.transaction_status.len(),
+                num_expired,
+                "Restored persisted consensus transaction rejections",
+            );
+            self.metrics
+                .consensus_tx_status_cache_size
+                .set(inner.transaction_status.len() as i64);
+            self.metrics
+                .consensus_tx_status_round_map_entries
+                .set(inner.round_lookup_map.len() as i64);
+        }
+        Ok(self)
+    }
+
+    /// Takes the changes to rejected positions made since the last call, to be written to the
+    /// epoch tables together with the outputs of the consensus commit being processed.
+    /// Always empty unless `persist_rejections` is configured.
+    pub(crate) fn take_pending_rejection_writes(
+        &self,
+    ) -> BTreeMap<ConsensusTxPosition, Option<ConsensusRejectionReason>> {
+        std::mem::take(&mut self.inner.write().pending_rejection_writes)
+    }
+
+    #[cfg(test)]
     pub fn set_transaction_status(
         &self,
         transaction_position: ConsensusTxPosition,
         status: ConsensusTxStatus,
     ) {
-        debug!(
-            "Setting transaction status for {:?}: {:?}",
-            transaction_position, status
-        );
-        let mut inner = self.inner.write();
-        if let Some(last_committed_leader_round) = *self.last_committed_leader_round_rx.borrow() {
-            if transaction_position.block.round as u64 + CONSENSUS_STATUS_RETENTION_ROUNDS
-                < last_committed_leader_round
-            {
-                return;
-            }
-        }
-        let old_status = inner
-            .transaction_status
-            .insert(transaction_position, status);
-        // Calls to set_transaction_status are async and can be out of order.
-        // We need to handle cases where new status is in fact older than the old status,
-        // or did not change.
-        if old_status == Some(status) {
-            return;
-        }
-        if let Some(old_status) = old_status {
-            if status == ConsensusTxStatus::FastpathCertified {
-                // If the new status is FastpathCertified, it must be older than the old status.
-                // We need to reset the status back to the old status.
+        self.set_transaction_statuses(std::iter::once((transaction_position, status)));
+    }
+
+    /// Sets the status of multiple transactions while acquiring the write lock only once.
+    /// Waiters are notified after all updates have been applied.
+    pub fn set_transaction_statuses(
+        &self,
+        updates: impl IntoIterator<Item = (ConsensusTxPosition, ConsensusTxStatus)>,
+    ) {
+        let mut notifications = vec![];
+        let mut evicted = vec![];
+        let last_committed_leader_round = {
+            let mut inner = self.inner.write();
+            let last_committed_leader_round = *self.last_committed_leader_round_rx.borrow();
+            let mut num_evicted = 0;
+            for (transaction_position, status) in updates {
+                debug!(
+                    "Setting transaction status for {:?}: {:?}",
+                    transaction_position, status
+                );
+                if let Some(last_committed_leader_round) = last_committed_leader_round {
+                    if transaction_position.block.round as u64 + self.retention_rounds
+                        < last_committed_leader_round
+                    {
+                        continue;
+                    }
+                }
+                let old_status = inner.transaction_status.get(&transaction_position).copied();
+                // Calls to set_transaction_status are async and can be out of order.
+                // We need to handle cases where new status is in fact older than the old status,
+                // or did not change.
+                match (old_status, status) {
+                    (None, _) => {
+                        // This is the first time we are setting the status for this transaction.
+                        // Make room for it if the cache is full, then add it to the round lookup
+                        // map to track its expiration.
+                        while inner.transaction_status.len() >= self.max_entries {
+                            let Some((position, old_status)) = inner.evict_oldest() else {
+                                break;
+                            };
+                            evicted.push(position);
+                            if self.persist_rejections {
+                                if let ConsensusTxStatus::Rejected(_) = old_status {
+                                    inner.pending_rejection_writes.insert(position, None);
+                                }
+                            }
+                            num_evicted += 1;
+                        }
+                        inner
+                            .round_lookup_map
+                            .entry(transaction_position.block.round as u64)
+                            .or_default()
+                            .insert(transaction_position);
+                    }
+                    // If the new status is FastpathCertified, it must be older than the old status.
+                    // Keep the old status.
+                    (Some(_), ConsensusTxStatus::FastpathCertified) => continue,
+                    // A rejection can be observed both when the block is certified and when it is
+                    // committed. Keep the reason of the first observed rejection.
+                    (Some(ConsensusTxStatus::Rejected(_)), ConsensusTxStatus::Rejected(_))
+                    | (Some(ConsensusTxStatus::Finalized), ConsensusTxStatus::Finalized) => {
+                        continue
+                    }
+                    (Some(ConsensusTxStatus::FastpathCertified), _) => {}
+                    (Some(old_status), _) => {
+                        // If neither old nor new status is FastpathCertified,
+                        // we must have a conflict (either from Rejected to Finalized, or from Finalized to Rejected).
+                        panic!(
+                            "Conflicting status updates for transaction {:?}: {:?} -> {:?}",
+                            transaction_position, old_status, status
+                        );
+                    }
+                }
                 inner
                     .transaction_status
-                    .insert(transaction_position, old_status);
-            } else if old_status != ConsensusTxStatus::FastpathCertified {
-                // If neither old nor new status is FastpathCertified,
-                // we must have a conflict (either from Rejected to Finalized, or from Finalized to Rejected).
-                panic!(
-                    "Conflicting status updates for transaction {:?}: {:?} -> {:?}",
-                    transaction_position, old_status, status
+                    .insert(transaction_position, status);
+                self.metrics
+                    .consensus_tx_status_updates
+                    .with_label_values(&[status.metric_label()])
+                    .inc();
+                if let ConsensusTxStatus::Rejected(reason) = status {
+                    // Only count rejections that were recorded, skipping expired and duplicate
+                    // reports.
+                    self.metrics
+                        .consensus_tx_rejections
+                        .with_label_values(&[reason.metric_label()])
+                        .inc();
+                    if !transaction_position.is_valid() {
+                        warn!(
+                            position = %transaction_position,
+                            "Rejecting transaction at an invalid consensus position"
+                        );
+                    }
+                    if self.persist_rejections {
+                        inner
+                            .pending_rejection_writes
+                            .insert(transaction_position, Some(reason));
+                    }
+                    let author = transaction_position.block.author;
+                    *inner.rejections_by_authority.entry(author).or_default() += 1;
+                    self.metrics
+                        .consensus_tx_rejections_by_authority
+                        .with_label_values(&[&author.value().to_string()])
+                        .inc();
+                }
+                notifications.push((transaction_position, status));
+            }
+            if num_evicted > 0 {
+                warn!(
+                    max_entries = self.max_entries,
+                    num_evicted,
+                    ?last_committed_leader_round,
+                    "Consensus tx status cache is full, evicted statuses of the oldest rounds",
                 );
             }
-        } else {
-            // This is the first time we are setting the status for this transaction.
-            // We need to add it to the round lookup map to track its expiration.
-            inner
-                .round_lookup_map
-                .entry(transaction_position.block.round as u64)
-                .or_default()
-                .insert(transaction_position);
+            self.metrics
+                .consensus_tx_status_cache_size
+                .set(inner.transaction_status.len() as i64);
+            self.metrics
+                .consensus_tx_status_round_map_entries
+                .set(inner.round_lookup_map.len() as i64);
+            last_committed_leader_round
+            // Inner write lock dropped here.
+        };
+        // Waiters of evicted positions would otherwise wait until the positions expire.
+        let expired =
+            NotifyReadConsensusTxStatusResult::Expired(last_committed_leader_round.unwrap_or(0));
+        for transaction_position in evicted {
+            self.status_notify_read
+                .notify(&transaction_position, &expired);
+        }
+        // Statuses are already visible to readers, so waiters registered before the update
+        // are notified here, and later readers observe the status directly.
+        for (transaction_position, status) in notifications {
+            if let ConsensusTxStatus::Rejected(reason) = status {
+                // Sending fails when there are no subscribers.
+                let _ = self.rejections_tx.send((transaction_position, reason));
+            }
+            self.status_notify_read.notify(
+                &transaction_position,
+                &NotifyReadConsensusTxStatusResult::Status(status),
+            );
         }
-        self.status_notify_read
-            .notify(&transaction_position, &status);
     }
 
+    /// Subscribes to all rejections observed from now on, e.g. for analytics. Each position is
+    /// received once, when it is first known to be rejected. A subscriber that falls more than
+    /// `CONSENSUS_REJECTION_SUBSCRIPTION_CAPACITY` rejections behind receives a `Lagged` error
+    /// and misses the oldest ones.
+    pub fn subscribe(
+        &self,
+    ) -> broadcast::Receiver<(ConsensusTxPosition, ConsensusRejectionReason)> {
+        self.rejections_tx.subscribe()
+    }
+
+    /// Waits until the status of the position differs from `old_status`, the position expires,
+    /// or the cache is cleared. Nothing is polled: expiration is checked whenever the last
+    /// committed leader round is updated, so it is detected as soon as the round is committed.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(round = transaction_position.block.round, index = transaction_position.index)
+    )]
     pub async fn notify_read_transaction_status(
         &self,
         transaction_position: ConsensusTxPosition,
@@ -123,6 +520,19 @@ impl ConsensusTxStatusCache {
     ) -> NotifyReadConsensusTxStatusResult {
         // TODO(fastpath): We should track the typical distance between the last committed round
         // and the requested round notified as metrics.
+        // An expired position can no longer receive status updates, so return without registering
+        // for notifications.
+        if let Some(last_committed_leader_round) = *self.last_committed_leader_round_rx.borrow() {
+            if transaction_position.block.round as u64 + self.retention_rounds
+                < last_committed_leader_round
+            {
+                self.metrics
+                    .consensus_tx_status_notify_read
+                    .with_label_values(&["expired"])
+                    .inc();
+                return NotifyReadConsensusTxStatusResult::Expired(last_committed_leader_round);
+            }
+        }
         let registration = self.status_notify_read.register_one(&transaction_position);
         let mut round_rx = self.last_committed_leader_round_rx.clone();
         {
@@ -134,57 +544,136 @@ impl ConsensusTxStatusCache {
                         // is initially fastpath certified, and then later finalized or rejected.
                         assert_eq!(old_status, ConsensusTxStatus::FastpathCertified);
                     }
+                    self.metrics
+                        .consensus_tx_status_notify_read
+                        .with_label_values(&[status.metric_label()])
+                        .inc();
                     return NotifyReadConsensusTxStatusResult::Status(*status);
                 }
             }
             // Inner read lock dropped here.
         }
 
+        // Only wakes up when the last committed leader round changes.
         let expiration_check = async {
-            loop {
-                if let Some(last_committed_leader_round) = *round_rx.borrow() {
-                    if transaction_position.block.round as u64 + CONSENSUS_STATUS_RETENTION_ROUNDS
-                        < last_committed_leader_round
-                    {
-                        return last_committed_leader_round;
-                    }
-                }
+            let last_committed_leader_round = round_rx
+                .wait_for(|last_committed_leader_round| {
+                    last_committed_leader_round.is_some_and(|round| {
+                        transaction_position.block.round as u64 + self.retention_rounds < round
+                    })
+                })
+                .await
                 // Channel closed - this should never happen in practice, so panic
-                round_rx
-                    .changed()
-                    .await
-                    .expect("last_committed_leader_round watch channel closed unexpectedly");
-            }
+                .expect("last_committed_leader_round watch channel closed unexpectedly");
+            // unwrap is safe because the predicate above only matches Some.
+            last_committed_leader_round.unwrap()
         };
-        tokio::select! {
-            status = registration => NotifyReadConsensusTxStatusResult::Status(status),
+        let result = tokio::select! {
+            result = registration => result,
             last_committed_leader_round = expiration_check => NotifyReadConsensusTxStatusResult::Expired(last_committed_leader_round),
-        }
+            _ = self.cleared_notify.wait() => NotifyReadConsensusTxStatusResult::Cleared,
+        };
+        let label = match &result {
+            NotifyReadConsensusTxStatusResult::Status(status) => status.metric_label(),
+            NotifyReadConsensusTxStatusResult::Expired(_) => "expired",
+            NotifyReadConsensusTxStatusResult::Cleared => "cleared",
+        };
+        self.metrics
+            .consensus_tx_status_notify_read
+            .with_label_values(&[label])
+            .inc();
+        result
     }
 
-    pub async fn update_last_committed_leader_round(&self, round: u64) {
+    /// Updates the last committed leader round and garbage collects statuses of positions
+    /// that fall out of the retention window. Returns the number of evicted positions.
+    ///
+    /// The last committed leader round never decreases within an epoch. A lower round is
+    /// reported as an invariant violation and ignored.
+    #[instrument(level = "debug", skip(self))]
+    pub fn update_last_committed_leader_round(&self, round: u64) -> usize {
         debug!("Updating last committed leader round: {}", round);
+        self.metrics.consensus_tx_status_committed_rounds_seen.inc();
         let mut inner = self.inner.write();
-        while let Some(&next_round) = inner.round_lookup_map.keys().next() {
-            if next_round + CONSENSUS_STATUS_RETENTION_ROUNDS < round {
-                let transactions = inner.round_lookup_map.remove(&next_round).unwrap();
-                for tx in transactions {
-                    inner.transaction_status.remove(&tx);
+        if let Some(last_committed_leader_round) = *self.last_committed_leader_round_rx.borrow() {
+            if round < last_committed_leader_round {
+                debug_fatal!(
+                    "Last committed leader round decreased from {} to {} in epoch {}, ignoring it",
+                    last_committed_leader_round,
+                    round,
+                    self.epoch,
+                );
+                return 0;
+            }
+        }
+        self.metrics
+            .consensus_tx_status_last_committed_round
+            .set(round as i64);
+        // Rounds below the cutoff are out of the retention window. Split them off in one step,
+        // instead of removing expired rounds one at a time.
+        let cutoff_round = round.saturating_sub(self.retention_rounds);
+        let retained = inner.round_lookup_map.split_off(&cutoff_round);
+        let expired = std::mem::replace(&mut inner.round_lookup_map, retained);
+        let mut num_evicted = 0;
+        for transactions in expired.values() {
+            num_evicted += transactions.len();
+            // Rejections of a round are final once it leaves the retention window, including
+            // post-commit rejections and rounds without a committed leader.
+            let mut num_rejected = 0;
+            for tx in transactions {
+                if let Some(ConsensusTxStatus::Rejected(_)) = inner.transaction_status.remove(tx) {
+                    num_rejected += 1;
+                    if self.persist_rejections {
+                        inner.pending_rejection_writes.insert(*tx, None);
+                    }
                 }
-            } else {
-                break;
             }
+            self.metrics
+                .consensus_tx_rejections_per_round
+                .observe(num_rejected as f64);
         }
+        self.metrics
+            .consensus_tx_status_expired
+            .inc_by(num_evicted as u64);
+        self.metrics
+            .consensus_tx_status_cache_size
+            .set(inner.transaction_status.len() as i64);
+        self.metrics
+            .consensus_tx_status_round_map_entries
+            .set(inner.round_lookup_map.len() as i64);
         // Send update through watch channel
         let _ = self.last_committed_leader_round_tx.send(Some(round));
+        drop(inner);
+
+        // Free the expired rounds without blocking writers, since rounds can hold many
+        // transactions.
+        drop(expired);
+        num_evicted
     }
 
-    /// Returns true if the position is too far ahead of the last committed round.
+    /// Removes all transaction statuses and resets the last committed leader round.
+    /// Used when the epoch ends. Pending and future reads return `Cleared` once this is called.
+    pub fn clear_all(&self) {
+        debug!("Clearing all consensus transaction statuses");
+        {
+            let mut inner = self.inner.write();
+            inner.transaction_status.clear();
+            inner.round_lookup_map.clear();
+            inner.rejections_by_authority.clear();
+            // The persisted rejections are dropped together with the epoch tables.
+            inner.pending_rejection_writes.clear();
+            self.metrics.consensus_tx_status_cache_size.set(0);
+            self.metrics.consensus_tx_status_round_map_entries.set(0);
+            self.last_committed_leader_round_tx.send_replace(None);
+        }
+        // The cache can be cleared more than once. Waiters are already released after the first call.
+        let _ = self.cleared_notify.notify();
+    }
+
+    /// Returns an error if the position is too far ahead of the last committed round.
     pub fn check_position_too_ahead(&self, position: &ConsensusTxPosition) -> SuiResult<()> {
         if let Some(last_committed_leader_round) = *self.last_committed_leader_round_rx.borrow() {
-            if position.block.round as u64
-                > last_committed_leader_round + CONSENSUS_STATUS_RETENTION_ROUNDS
-            {
+            if position.block.round as u64 > last_committed_leader_round + self.retention_rounds {
                 return Err(SuiError::ValidatorConsensusLagging {
                     round: position.block.round as u64,
                     last_committed_round: last_committed_leader_round,
@@ -194,6 +683,73 @@ impl ConsensusTxStatusCache {
         Ok(())
     }
 
+    /// Returns the rejection reason if the transaction at the position is known to be rejected
+    /// by consensus. Unlike notify_read_transaction_status, this does not wait for status updates.
+    pub fn rejection_reason(
+        &self,
+        position: &ConsensusTxPosition,
+    ) -> Option<ConsensusRejectionReason> {
+        match self.inner.read().transaction_status.get(position) {
+            Some(ConsensusTxStatus::Rejected(reason)) => Some(*reason),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the transaction at the position has been rejected by consensus.
+    pub fn is_rejected(&self, position: &ConsensusTxPosition) -> bool {
+        self.rejection_reason(position).is_some()
+    }
+
+    /// Returns the positions of all transactions currently known to be rejected,
+    /// in position order.
+    pub fn snapshot(&self) -> Vec<ConsensusTxPosition> {
+        let mut positions: Vec<_> = self
+            .inner
+            .read()
+            .transaction_status
+            .iter()
+            .filter(|(_, status)| matches!(status, ConsensusTxStatus::Rejected(_)))
+            .map(|(position, _)| *position)
+            .collect();
+        positions.sort();
+        positions
+    }
+
+    /// Returns the positions of transactions in blocks of the given round that are currently
+    /// known to be rejected, in position order.
+    pub fn snapshot_by_round(&self, round: u64) -> Vec<ConsensusTxPosition> {
+        self.inner
+            .read()
+            .rejected_in_round(round)
+            .copied()
+            .collect()
+    }
+
+    /// Returns the number of positions rejected so far, by the authority of the proposing block.
+    /// Rejections stay counted after their positions are garbage collected, until the cache is
+    /// cleared.
+    pub fn rejection_counts_by_authority(&self) -> HashMap<AuthorityIndex, u64> {
+        self.inner.read().rejections_by_authority.clone()
+    }
+
+    /// Returns a read-only view of the transaction statuses, consistent until it is dropped.
+    pub fn view(&self) -> ConsensusTxStatusCacheView<'_> {
+        ConsensusTxStatusCacheView {
+            inner: self.inner.read(),
+        }
+    }
+
+    /// Returns the number of transaction positions with a known status.
+    /// The same value is reported by the consensus_tx_status_cache_size metric.
+    pub fn len(&self) -> usize {
+        self.inner.read().transaction_status.len()
+    }
+
+    /// Returns true if no transaction position has a known status.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     #[cfg(test)]
     pub fn get_transaction_status(
         &self,
@@ -209,24 +765,31 @@ mod tests {
     use std::{sync::Arc, time::Duration};
 
     use super::*;
+    use crate::authority::authority_per_epoch_store::AuthorityEpochTables;
     use consensus_core::BlockRef;
     use futures::FutureExt;
+    use prometheus::Registry;
+    use rstest::rstest;
     use sui_types::messages_consensus::TransactionIndex;
 
+    fn test_metrics() -> Arc<EpochMetrics> {
+        EpochMetrics::new(&Registry::new())
+    }
+
     fn create_test_tx_position(round: u64, index: u64) -> ConsensusTxPosition {
-        ConsensusTxPosition {
-            block: BlockRef {
+        ConsensusTxPosition::new(
+            BlockRef {
                 round: round as u32,
                 author: Default::default(),
                 digest: Default::default(),
             },
-            index: index as TransactionIndex,
-        }
+            index as TransactionIndex,
+        )
     }
 
     #[tokio::test]
     async fn test_set_and_get_transaction_status() {
-        let cache = ConsensusTxStatusCache::new();
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
         let tx_pos = create_test_tx_position(1, 0);
 
         // Set initial status
@@ -240,9 +803,38 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_read_already_rejected_status() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+
+        // Reads of an already rejected position return immediately with the exact reason.
+        for (index, reason) in [
+            ConsensusRejectionReason::FastPath,
+            ConsensusRejectionReason::PostCommit,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let tx_pos = create_test_tx_position(1, index as u64);
+            cache.set_transaction_status(tx_pos, ConsensusTxStatus::Rejected(reason));
+            let result = cache
+                .notify_read_transaction_status(tx_pos, None)
+                .now_or_never()
+                .expect("Rejected status should be returned immediately");
+            let NotifyReadConsensusTxStatusResult::Status(status) = result else {
+                panic!("Unexpected result: {:?}", result);
+            };
+            assert_eq!(status, ConsensusTxStatus::Rejected(reason));
+        }
+    }
+
     #[tokio::test]
     async fn test_status_notification() {
-        let cache = Arc::new(ConsensusTxStatusCache::new());
+        let cache = Arc::new(ConsensusTxStatusCache::new(
+            0,
+            Default::default(),
+            test_metrics(),
+        ));
         let tx_pos = create_test_tx_position(1, 0);
 
         // Spawn a task that waits for status update
@@ -269,16 +861,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_round_expiration() {
-        let cache = ConsensusTxStatusCache::new();
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
         let tx_pos = create_test_tx_position(1, 0);
 
         // Set initial status
         cache.set_transaction_status(tx_pos, ConsensusTxStatus::FastpathCertified);
 
         // Update last committed round to trigger expiration
-        cache
-            .update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 2)
-            .await;
+        cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 2);
 
         // Try to read status - should be expired
         let result = cache.notify_read_transaction_status(tx_pos, None).await;
@@ -288,9 +878,97 @@ mod tests {
         ));
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_round_expiration_with_retention_rounds(
+        #[values(1, 10, CONSENSUS_STATUS_RETENTION_ROUNDS)] retention_rounds: u64,
+    ) {
+        let cache = ConsensusTxStatusCache::new(
+            0,
+            ConsensusTxStatusCacheConfig {
+                retention_rounds,
+                ..Default::default()
+            },
+            test_metrics(),
+        );
+        let tx_pos = create_test_tx_position(1, 0);
+        cache.set_transaction_status(tx_pos, ConsensusTxStatus::FastpathCertified);
+
+        // The position is still retained at the boundary of the retention window.
+        cache.update_last_committed_leader_round(retention_rounds + 1);
+        let result = cache.notify_read_transaction_status(tx_pos, None).await;
+        assert!(matches!(
+            result,
+            NotifyReadConsensusTxStatusResult::Status(ConsensusTxStatus::FastpathCertified)
+        ));
+
+        // The position expires once the last committed round moves past the retention window.
+        cache.update_last_committed_leader_round(retention_rounds + 2);
+        let result = cache.notify_read_transaction_status(tx_pos, None).await;
+        assert!(matches!(
+            result,
+            NotifyReadConsensusTxStatusResult::Expired(_)
+        ));
+
+        // Positions too far ahead of the last committed round are rejected.
+        let ahead_pos = create_test_tx_position(2 * retention_rounds + 3, 0);
+        assert!(cache.check_position_too_ahead(&ahead_pos).is_err());
+        let ahead_pos = create_test_tx_position(2 * retention_rounds + 2, 0);
+        assert!(cache.check_position_too_ahead(&ahead_pos).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_read_already_expired_position() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        let tx_pos = create_test_tx_position(1, 0);
+        let last_committed_round = CONSENSUS_STATUS_RETENTION_ROUNDS + 2;
+        cache.update_last_committed_leader_round(last_committed_round);
+
+        // Reads of an expired position return immediately without registering a notification.
+        let result = cache
+            .notify_read_transaction_status(tx_pos, None)
+            .now_or_never()
+            .expect("Expired position should be returned immediately");
+        assert!(matches!(
+            result,
+            NotifyReadConsensusTxStatusResult::Expired(round) if round == last_committed_round
+        ));
+        assert_eq!(cache.status_notify_read.num_pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_expiration_on_round_update() {
+        let cache = Arc::new(ConsensusTxStatusCache::new(
+            0,
+            Default::default(),
+            test_metrics(),
+        ));
+        let tx_pos = create_test_tx_position(1, 0);
+
+        let cache_clone = cache.clone();
+        let handle = tokio::spawn(async move {
+            cache_clone
+                .notify_read_transaction_status(tx_pos, None)
+                .await
+        });
+
+        // Round updates within the retention window do not expire the waiter.
+        cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 1);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!handle.is_finished());
+
+        // The waiter expires as soon as the round moves past the retention window.
+        cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 2);
+        let result = handle.await.unwrap();
+        let NotifyReadConsensusTxStatusResult::Expired(round) = result else {
+            panic!("Unexpected result: {:?}", result);
+        };
+        assert_eq!(round, CONSENSUS_STATUS_RETENTION_ROUNDS + 2);
+    }
+
     #[tokio::test]
     async fn test_multiple_status_updates() {
-        let cache = ConsensusTxStatusCache::new();
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
         let tx_pos = create_test_tx_position(1, 0);
 
         // Set initial status
@@ -311,7 +989,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_cleanup_expired_rounds() {
-        let cache = ConsensusTxStatusCache::new();
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
 
         // Add transactions for multiple rounds
         for round in 1..=5 {
@@ -320,11 +998,10 @@ mod tests {
         }
 
         // Update last committed round to expire early rounds
-        cache
-            .update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 3)
-            .await;
+        cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 3);
 
         // Verify early rounds are cleaned up
+        assert_eq!(cache.len(), 3);
         let inner = cache.inner.read();
         assert!(!inner.round_lookup_map.contains_key(&1));
         assert!(!inner.round_lookup_map.contains_key(&2));
@@ -332,9 +1009,89 @@ mod tests {
         assert!(inner.round_lookup_map.contains_key(&5));
     }
 
+    #[tokio::test]
+    async fn test_evicted_count() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        assert!(cache.is_empty());
+
+        // Add 2 transactions in each of rounds 1 to 5.
+        for round in 1..=5 {
+            for index in 0..2 {
+                let tx_pos = create_test_tx_position(round, index);
+                cache.set_transaction_status(tx_pos, ConsensusTxStatus::FastpathCertified);
+            }
+        }
+        assert_eq!(cache.len(), 10);
+
+        // Nothing is evicted while all rounds are within the retention window.
+        assert_eq!(
+            cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 1),
+            0
+        );
+
+        // Rounds 1 and 2 are evicted.
+        assert_eq!(
+            cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 3),
+            4
+        );
+
+        // Already evicted rounds are not counted again.
+        assert_eq!(
+            cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 4),
+            2
+        );
+        assert_eq!(cache.len(), 4);
+    }
+
+    #[test]
+    fn test_update_last_committed_leader_round_without_runtime() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        let tx_pos = create_test_tx_position(1, 0);
+        cache.set_transaction_status(tx_pos, ConsensusTxStatus::FastpathCertified);
+
+        assert_eq!(cache.update_last_committed_leader_round(1), 0);
+        assert_eq!(
+            cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 2),
+            1
+        );
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_evict_many_rounds() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        let num_rounds = 10_000;
+        for round in 1..=num_rounds {
+            let tx_pos = create_test_tx_position(round, 0);
+            cache.set_transaction_status(tx_pos, ConsensusTxStatus::FastpathCertified);
+        }
+
+        // Rounds below 5000 fall out of the retention window.
+        let last_committed_round = 5_000 + CONSENSUS_STATUS_RETENTION_ROUNDS;
+        assert_eq!(
+            cache.update_last_committed_leader_round(last_committed_round),
+            4_999
+        );
+        assert_eq!(cache.len(), (num_rounds - 4_999) as usize);
+        assert!(cache
+            .get_transaction_status(&create_test_tx_position(4_999, 0))
+            .is_none());
+        assert!(cache
+            .get_transaction_status(&create_test_tx_position(5_000, 0))
+            .is_some());
+        assert_eq!(
+            cache.inner.read().round_lookup_map.keys().next().copied(),
+            Some(5_000)
+        );
+    }
+
     #[tokio::test]
     async fn test_concurrent_operations() {
-        let cache = Arc::new(ConsensusTxStatusCache::new());
+        let cache = Arc::new(ConsensusTxStatusCache::new(
+            0,
+            Default::default(),
+            test_metrics(),
+        ));
         let tx_pos = create_test_tx_position(1, 0);
 
         // Spawn multiple tasks that wait for status
@@ -366,26 +1123,810 @@ mod tests {
 
     #[tokio::test]
     async fn test_out_of_order_status_updates() {
-        let cache = ConsensusTxStatusCache::new();
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
         let tx_pos = create_test_tx_position(1, 0);
+        let rejected = ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath);
 
         // First update status to Rejected
-        cache.set_transaction_status(tx_pos, ConsensusTxStatus::Rejected);
+        cache.set_transaction_status(tx_pos, rejected);
         let result = cache.notify_read_transaction_status(tx_pos, None).await;
         assert!(matches!(
             result,
-            NotifyReadConsensusTxStatusResult::Status(ConsensusTxStatus::Rejected)
+            NotifyReadConsensusTxStatusResult::Status(ConsensusTxStatus::Rejected(
+                ConsensusRejectionReason::FastPath
+            ))
         ));
 
         // We should not receive a new status update since the new status is older than the old status.
         cache.set_transaction_status(tx_pos, ConsensusTxStatus::FastpathCertified);
         let result = cache
-            .notify_read_transaction_status(tx_pos, Some(ConsensusTxStatus::Rejected))
+            .notify_read_transaction_status(tx_pos, Some(rejected))
             .now_or_never();
         assert!(result.is_none());
+        assert_eq!(cache.get_transaction_status(&tx_pos), Some(rejected));
+    }
+
+    #[tokio::test]
+    async fn test_rejection_reasons() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+
+        // A transaction rejected after being fastpath certified is rejected post commit.
+        let tx_pos = create_test_tx_position(1, 0);
+        cache.set_transaction_status(tx_pos, ConsensusTxStatus::FastpathCertified);
+        cache.set_transaction_status(
+            tx_pos,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+        );
+        let result = cache
+            .notify_read_transaction_status(tx_pos, Some(ConsensusTxStatus::FastpathCertified))
+            .await;
+        assert!(matches!(
+            result,
+            NotifyReadConsensusTxStatusResult::Status(ConsensusTxStatus::Rejected(
+                ConsensusRejectionReason::PostCommit
+            ))
+        ));
+
+        // A transaction rejected on fastpath and then again in the commit keeps the first reason.
+        let tx_pos = create_test_tx_position(1, 1);
+        cache.set_transaction_status(
+            tx_pos,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+        );
+        cache.set_transaction_status(
+            tx_pos,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+        );
         assert_eq!(
             cache.get_transaction_status(&tx_pos),
-            Some(ConsensusTxStatus::Rejected)
+            Some(ConsensusTxStatus::Rejected(
+                ConsensusRejectionReason::FastPath
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_is_rejected() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        let tx_pos = create_test_tx_position(1, 0);
+
+        // Unknown positions are not rejected.
+        assert!(!cache.is_rejected(&tx_pos));
+
+        cache.set_transaction_status(tx_pos, ConsensusTxStatus::FastpathCertified);
+        assert!(!cache.is_rejected(&tx_pos));
+
+        cache.set_transaction_status(
+            tx_pos,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
         );
+        assert!(cache.is_rejected(&tx_pos));
+
+        let finalized_pos = create_test_tx_position(1, 1);
+        cache.set_transaction_status(finalized_pos, ConsensusTxStatus::Finalized);
+        assert!(!cache.is_rejected(&finalized_pos));
+
+        // Expired positions are no longer known to be rejected.
+        cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 2);
+        assert!(!cache.is_rejected(&tx_pos));
+    }
+
+    #[tokio::test]
+    async fn test_clear_all() {
+        let cache = Arc::new(ConsensusTxStatusCache::new(
+            0,
+            Default::default(),
+            test_metrics(),
+        ));
+        let rejected_pos = create_test_tx_position(1, 0);
+        let pending_pos = create_test_tx_position(2, 0);
+        cache.set_transaction_status(
+            rejected_pos,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+        );
+        cache.update_last_committed_leader_round(2);
+
+        // Spawn a task that waits for a status that is never set.
+        let cache_clone = cache.clone();
+        let handle = tokio::spawn(async move {
+            cache_clone
+                .notify_read_transaction_status(pending_pos, None)
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        cache.clear_all();
+
+        // The pending read is released.
+        let result = handle.await.unwrap();
+        assert!(matches!(result, NotifyReadConsensusTxStatusResult::Cleared));
+
+        // All state is removed.
+        assert!(cache.is_empty());
+        assert!(!cache.is_rejected(&rejected_pos));
+        assert!(cache.snapshot().is_empty());
+        assert!(cache.inner.read().round_lookup_map.is_empty());
+        assert!(cache.rejection_counts_by_authority().is_empty());
+        assert_eq!(*cache.last_committed_leader_round_rx.borrow(), None);
+
+        // Later reads return immediately, and clearing again is a no-op.
+        let result = cache
+            .notify_read_transaction_status(rejected_pos, None)
+            .now_or_never()
+            .expect("Read should return immediately after the cache is cleared");
+        assert!(matches!(result, NotifyReadConsensusTxStatusResult::Cleared));
+        cache.clear_all();
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Last committed leader round decreased")]
+    async fn test_round_regression_is_invariant_violation() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        cache.update_last_committed_leader_round(1000);
+
+        // A lower round is an invariant violation. It is ignored in production and panics in
+        // tests.
+        cache.update_last_committed_leader_round(5);
+    }
+
+    #[test]
+    fn test_debug_format() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        cache.set_transaction_statuses([
+            (
+                create_test_tx_position(1, 0),
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+            ),
+            (create_test_tx_position(1, 1), ConsensusTxStatus::Finalized),
+        ]);
+        assert_eq!(
+            format!("{:?}", cache),
+            "ConsensusTxStatusCache { epoch: 0, retention_rounds: 400, status_count: 2, rejected_count: 1, .. }"
+        );
+
+        // Formatting does not wait for the lock.
+        let _guard = cache.inner.write();
+        assert_eq!(
+            format!("{:?}", cache),
+            "ConsensusTxStatusCache { epoch: 0, retention_rounds: 400, status_count: <locked>, rejected_count: <locked>, .. }"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_snapshot() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        assert!(cache.snapshot().is_empty());
+
+        let rejected = ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath);
+        cache.set_transaction_statuses([
+            (create_test_tx_position(3, 0), rejected),
+            (create_test_tx_position(1, 2), rejected),
+            (create_test_tx_position(1, 1), ConsensusTxStatus::Finalized),
+            (
+                create_test_tx_position(2, 0),
+                ConsensusTxStatus::FastpathCertified,
+            ),
+            (create_test_tx_position(1, 0), rejected),
+        ]);
+
+        // Only rejected positions are returned, in sorted order.
+        assert_eq!(
+            cache.snapshot(),
+            vec![
+                create_test_tx_position(1, 0),
+                create_test_tx_position(1, 2),
+                create_test_tx_position(3, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_by_round() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        let rejected = ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit);
+        cache.set_transaction_statuses([
+            (create_test_tx_position(2, 3), rejected),
+            (create_test_tx_position(1, 1), rejected),
+            (create_test_tx_position(2, 0), rejected),
+            (create_test_tx_position(1, 0), ConsensusTxStatus::Finalized),
+            (create_test_tx_position(3, 0), ConsensusTxStatus::Finalized),
+            (create_test_tx_position(2, 1), rejected),
+        ]);
+
+        assert_eq!(
+            cache.snapshot_by_round(1),
+            vec![create_test_tx_position(1, 1)]
+        );
+        assert_eq!(
+            cache.snapshot_by_round(2),
+            vec![
+                create_test_tx_position(2, 0),
+                create_test_tx_position(2, 1),
+                create_test_tx_position(2, 3),
+            ]
+        );
+        // Rounds without rejections, or without any known position, are empty.
+        assert!(cache.snapshot_by_round(3).is_empty());
+        assert!(cache.snapshot_by_round(4).is_empty());
+
+        // Positions garbage collected with their round are no longer returned.
+        cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 2);
+        assert!(cache.snapshot_by_round(1).is_empty());
+        assert_eq!(cache.snapshot_by_round(2).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_batch_status_updates() {
+        let cache = Arc::new(ConsensusTxStatusCache::new(
+            0,
+            Default::default(),
+            test_metrics(),
+        ));
+        let positions: Vec<_> = (0..10).map(|i| create_test_tx_position(1, i)).collect();
+
+        // Spawn a task waiting on each position.
+        let mut handles = vec![];
+        for tx_pos in positions.iter().copied() {
+            let cache_clone = cache.clone();
+            handles.push(tokio::spawn(async move {
+                cache_clone
+                    .notify_read_transaction_status(tx_pos, None)
+                    .await
+            }));
+        }
+
+        // Small delay to ensure tasks are waiting
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // Reject all positions in a single batch.
+        let rejected = ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit);
+        cache.set_transaction_statuses(positions.iter().map(|tx_pos| (*tx_pos, rejected)));
+
+        // Verify all waiters were unblocked.
+        for handle in handles {
+            let result = handle.await.unwrap();
+            assert!(matches!(
+                result,
+                NotifyReadConsensusTxStatusResult::Status(ConsensusTxStatus::Rejected(
+                    ConsensusRejectionReason::PostCommit
+                ))
+            ));
+        }
+        for tx_pos in &positions {
+            assert_eq!(cache.get_transaction_status(tx_pos), Some(rejected));
+        }
+    }
+
+    #[test]
+    fn test_view() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        assert!(cache.view().is_empty());
+
+        let finalized = create_test_tx_position(1, 0);
+        let rejected = create_test_tx_position(2, 0);
+        let rejection = ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit);
+        cache.set_transaction_statuses([
+            (finalized, ConsensusTxStatus::Finalized),
+            (rejected, rejection),
+        ]);
+
+        let view = cache.view();
+        assert_eq!(view.len(), 2);
+        assert!(view.contains(&finalized));
+        assert!(!view.contains(&create_test_tx_position(3, 0)));
+        assert_eq!(view.get(&rejected), Some(rejection));
+        let mut statuses: Vec<_> = view.iter().map(|(p, s)| (*p, *s)).collect();
+        statuses.sort_by_key(|(position, _)| *position);
+        assert_eq!(
+            statuses,
+            vec![
+                (finalized, ConsensusTxStatus::Finalized),
+                (rejected, rejection)
+            ]
+        );
+        // The view holds the read lock until dropped.
+        assert!(cache.inner.try_write().is_none());
+        drop(view);
+        assert!(cache.inner.try_write().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_rejections() {
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), test_metrics());
+        let mut rx = cache.subscribe();
+
+        let fastpath_rejected = create_test_tx_position(1, 0);
+        let post_commit_rejected = create_test_tx_position(2, 0);
+        cache.set_transaction_statuses([
+            (
+                fastpath_rejected,
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+            ),
+            (create_test_tx_position(1, 1), ConsensusTxStatus::Finalized),
+            (post_commit_rejected, ConsensusTxStatus::FastpathCertified),
+        ]);
+        cache.set_transaction_statuses([
+            // Repeated rejections are not broadcast again.
+            (
+                fastpath_rejected,
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+            ),
+            (
+                post_commit_rejected,
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+            ),
+        ]);
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            (fastpath_rejected, ConsensusRejectionReason::FastPath)
+        );
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            (post_commit_rejected, ConsensusRejectionReason::PostCommit)
+        );
+        assert!(matches!(
+            rx.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+
+        // Subscribers only receive rejections observed after they subscribe.
+        let mut late_rx = cache.subscribe();
+        assert!(matches!(
+            late_rx.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejection_counts_by_authority() {
+        let metrics = test_metrics();
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), metrics.clone());
+        let position = |author: u32, index| {
+            ConsensusTxPosition::new(
+                BlockRef {
+                    round: 1,
+                    author: AuthorityIndex::new_for_test(author),
+                    digest: Default::default(),
+                },
+                index,
+            )
+        };
+
+        cache.set_transaction_statuses([
+            (
+                position(0, 0),
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+            ),
+            (
+                position(2, 0),
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+            ),
+            (
+                position(2, 1),
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+            ),
+            (position(1, 0), ConsensusTxStatus::FastpathCertified),
+        ]);
+        // A rejection reported again at another stage is counted once.
+        cache.set_transaction_status(
+            position(0, 0),
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+        );
+
+        let counts = cache.rejection_counts_by_authority();
+        assert_eq!(
+            counts,
+            HashMap::from([
+                (AuthorityIndex::new_for_test(0), 1),
+                (AuthorityIndex::new_for_test(2), 2),
+            ])
+        );
+        for (authority_index, count) in [("0", 1), ("1", 0), ("2", 2)] {
+            assert_eq!(
+                metrics
+                    .consensus_tx_rejections_by_authority
+                    .with_label_values(&[authority_index])
+                    .get(),
+                count
+            );
+        }
+
+        // Counts survive garbage collection of the rejected positions.
+        cache.update_last_committed_leader_round(1000);
+        assert!(cache.is_empty());
+        assert_eq!(cache.rejection_counts_by_authority(), counts);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_updates() {
+        let metrics = test_metrics();
+        let cache = ConsensusTxStatusCache::new(0, Default::default(), metrics.clone());
+
+        let certified_pos = create_test_tx_position(1, 0);
+        let rejected_pos = create_test_tx_position(1, 1);
+        cache.set_transaction_status(certified_pos, ConsensusTxStatus::FastpathCertified);
+        cache.set_transaction_status(
+            rejected_pos,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+        );
+        // Duplicate rejection is not counted as an update.
+        cache.set_transaction_status(
+            rejected_pos,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+        );
+        assert_eq!(
+            metrics
+                .consensus_tx_status_updates
+                .with_label_values(&["fastpath_certified"])
+                .get(),
+            1
+        );
+        assert_eq!(
+            metrics
+                .consensus_tx_status_updates
+                .with_label_values(&["rejected_fastpath"])
+                .get(),
+            1
+        );
+        assert_eq!(
+            metrics
+                .consensus_tx_status_updates
+                .with_label_values(&["rejected_post_commit"])
+                .get(),
+            0
+        );
+        // Only the first reported rejection is counted.
+        assert_eq!(
+            metrics
+                .consensus_tx_rejections
+                .with_label_values(&["fastpath"])
+                .get(),
+            1
+        );
+        assert_eq!(
+            metrics
+                .consensus_tx_rejections
+                .with_label_values(&["post_commit"])
+                .get(),
+            0
+        );
+        assert_eq!(metrics.consensus_tx_status_cache_size.get(), 2);
+        assert_eq!(metrics.consensus_tx_status_round_map_entries.get(), 1);
+
+        cache
+            .notify_read_transaction_status(rejected_pos, None)
+            .await;
+        assert_eq!(
+            metrics
+                .consensus_tx_status_notify_read
+                .with_label_values(&["rejected_fastpath"])
+                .get(),
+            1
+        );
+
+        cache.update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 2);
+        assert_eq!(metrics.consensus_tx_status_expired.get(), 2);
+        assert_eq!(metrics.consensus_tx_status_cache_size.get(), 0);
+        assert_eq!(metrics.consensus_tx_status_round_map_entries.get(), 0);
+        assert_eq!(metrics.consensus_tx_status_committed_rounds_seen.get(), 1);
+        assert_eq!(
+            metrics.consensus_tx_status_last_committed_round.get(),
+            CONSENSUS_STATUS_RETENTION_ROUNDS as i64 + 2
+        );
+
+        cache
+            .notify_read_transaction_status(certified_pos, None)
+            .await;
+        assert_eq!(
+            metrics
+                .consensus_tx_status_notify_read
+                .with_label_values(&["expired"])
+                .get(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejections_per_round_metric() {
+        let metrics = test_metrics();
+        let config = ConsensusTxStatusCacheConfig {
+            retention_rounds: 1,
+            ..Default::default()
+        };
+        let cache = ConsensusTxStatusCache::new(0, config, metrics.clone());
+        let rejected = ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath);
+        cache.set_transaction_statuses([
+            (create_test_tx_position(3, 0), rejected),
+            (create_test_tx_position(3, 1), rejected),
+            (create_test_tx_position(3, 2), ConsensusTxStatus::Finalized),
+            (create_test_tx_position(4, 0), rejected),
+            (create_test_tx_position(5, 0), ConsensusTxStatus::Finalized),
+        ]);
+
+        // Nothing is recorded until a round leaves the retention window.
+        let histogram = &metrics.consensus_tx_rejections_per_round;
+        cache.update_last_committed_leader_round(3);
+        assert_eq!(histogram.get_sample_count(), 0);
+
+        // Rejections observed after the round is committed are recorded with the round.
+        cache.set_transaction_status(
+            create_test_tx_position(3, 3),
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+        );
+        cache.update_last_committed_leader_round(5);
+        assert_eq!(histogram.get_sample_count(), 1);
+        assert_eq!(histogram.get_sample_sum(), 3.0);
+
+        // Each expired round is recorded, including rounds that were never committed as
+        // leader rounds and rounds without rejections.
+        cache.update_last_committed_leader_round(7);
+        assert_eq!(histogram.get_sample_count(), 3);
+        assert_eq!(histogram.get_sample_sum(), 4.0);
+    }
+
+    #[tokio::test]
+    async fn test_persisted_rejections() {
+        let dir = tempfile::tempdir().unwrap();
+        let tables = AuthorityEpochTables::open(0, dir.path(), None);
+        let table = tables.consensus_tx_rejections.clone();
+        let config = ConsensusTxStatusCacheConfig {
+            retention_rounds: 2,
+            persist_rejections: true,
+            ..Default::default()
+        };
+        let cache = ConsensusTxStatusCache::new(0, config.clone(), test_metrics())
+            .with_rejections_table(&table, None)
+            .unwrap();
+        let expired = create_test_tx_position(1, 0);
+        let finalized = create_test_tx_position(1, 1);
+        let rejected = create_test_tx_position(5, 0);
+        cache.set_transaction_statuses([
+            (
+                expired,
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+            ),
+            (finalized, ConsensusTxStatus::Finalized),
+            (
+                rejected,
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+            ),
+        ]);
+        // Only rejections are buffered, until they are taken to be written with a commit.
+        let writes = cache.take_pending_rejection_writes();
+        assert_eq!(
+            writes,
+            BTreeMap::from([
+                (expired, Some(ConsensusRejectionReason::FastPath)),
+                (rejected, Some(ConsensusRejectionReason::PostCommit)),
+            ])
+        );
+        assert!(cache.take_pending_rejection_writes().is_empty());
+        table
+            .multi_insert(
+                writes
+                    .into_iter()
+                    .map(|(position, reason)| (position, reason.unwrap())),
+            )
+            .unwrap();
+        drop(cache);
+
+        // After a restart, rejections are restored unless they have expired, in which case
+        // they are deleted with the next commit.
+        let restored = ConsensusTxStatusCache::new(0, config, test_metrics())
+            .with_rejections_table(&table, Some(4))
+            .unwrap();
+        assert_eq!(
+            restored.rejection_reason(&rejected),
+            Some(ConsensusRejectionReason::PostCommit)
+        );
+        assert_eq!(restored.rejection_reason(&expired), None);
+        assert_eq!(
+            restored.take_pending_rejection_writes(),
+            BTreeMap::from([(expired, None)])
+        );
+
+        // Evicted rejections are deleted.
+        restored.update_last_committed_leader_round(8);
+        assert_eq!(
+            restored.take_pending_rejection_writes(),
+            BTreeMap::from([(rejected, None)])
+        );
+
+        // Without persistence configured, the table is not read and nothing is buffered.
+        let unpersisted = ConsensusTxStatusCache::new(0, Default::default(), test_metrics())
+            .with_rejections_table(&table, None)
+            .unwrap();
+        assert_eq!(unpersisted.rejection_reason(&rejected), None);
+        unpersisted.set_transaction_status(
+            rejected,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+        );
+        assert!(unpersisted.take_pending_rejection_writes().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_max_entries_evicts_oldest_rounds() {
+        let config = ConsensusTxStatusCacheConfig {
+            max_entries: 3,
+            ..Default::default()
+        };
+        let cache = ConsensusTxStatusCache::new(0, config, test_metrics());
+        let oldest = create_test_tx_position(1, 0);
+        let older = create_test_tx_position(1, 1);
+        cache.set_transaction_statuses([
+            (create_test_tx_position(3, 0), ConsensusTxStatus::Finalized),
+            (older, ConsensusTxStatus::FastpathCertified),
+            (
+                oldest,
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+            ),
+        ]);
+        assert_eq!(cache.len(), 3);
+
+        // Updating a tracked position does not evict anything.
+        cache.set_transaction_status(older, ConsensusTxStatus::Finalized);
+        assert_eq!(cache.len(), 3);
+
+        // New positions evict the oldest rounds first.
+        cache.set_transaction_statuses([
+            (create_test_tx_position(4, 0), ConsensusTxStatus::Finalized),
+            (create_test_tx_position(5, 0), ConsensusTxStatus::Finalized),
+        ]);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get_transaction_status(&oldest), None);
+        assert_eq!(cache.get_transaction_status(&older), None);
+        assert_eq!(
+            cache.get_transaction_status(&create_test_tx_position(3, 0)),
+            Some(ConsensusTxStatus::Finalized)
+        );
+        assert_eq!(cache.metrics.consensus_tx_status_round_map_entries.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_max_entries_eviction_wakes_waiters() {
+        let config = ConsensusTxStatusCacheConfig {
+            max_entries: 1,
+            ..Default::default()
+        };
+        let cache = Arc::new(ConsensusTxStatusCache::new(0, config, test_metrics()));
+        let evicted = create_test_tx_position(1, 0);
+        cache.set_transaction_status(evicted, ConsensusTxStatus::FastpathCertified);
+
+        let cache_clone = cache.clone();
+        let wait = tokio::spawn(async move {
+            cache_clone
+                .notify_read_transaction_status(evicted, Some(ConsensusTxStatus::FastpathCertified))
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!wait.is_finished());
+
+        // Evicting the position releases its waiter, without waiting for it to expire.
+        cache.set_transaction_status(
+            create_test_tx_position(2, 0),
+            ConsensusTxStatus::FastpathCertified,
+        );
+        let result = tokio::time::timeout(Duration::from_secs(1), wait)
+            .await
+            .expect("Waiter of an evicted position should be released")
+            .unwrap();
+        assert!(matches!(
+            result,
+            NotifyReadConsensusTxStatusResult::Expired(0)
+        ));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_rejection_notified_while_waiting() {
+        let cache = Arc::new(ConsensusTxStatusCache::new(
+            0,
+            Default::default(),
+            test_metrics(),
+        ));
+        let tx_pos = create_test_tx_position(1, 0);
+
+        // Reject the transaction only after the wait below has started.
+        let cache_clone = cache.clone();
+        let reject = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            cache_clone.set_transaction_status(
+                tx_pos,
+                ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+            );
+        });
+
+        let start = tokio::time::Instant::now();
+        let result = cache.notify_read_transaction_status(tx_pos, None).await;
+        assert!(matches!(
+            result,
+            NotifyReadConsensusTxStatusResult::Status(ConsensusTxStatus::Rejected(
+                ConsensusRejectionReason::FastPath
+            ))
+        ));
+        // The wait returns as soon as the rejection is set.
+        assert_eq!(start.elapsed(), Duration::from_millis(100));
+        reject.await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_rejections_and_round_updates() {
+        const NUM_REJECTIONS: u64 = 1000;
+        const MAX_ROUND: u64 = 500;
+        const RETENTION_ROUNDS: u64 = 10;
+        let config = ConsensusTxStatusCacheConfig {
+            retention_rounds: RETENTION_ROUNDS,
+            ..Default::default()
+        };
+        let cache = Arc::new(ConsensusTxStatusCache::new(0, config, test_metrics()));
+
+        // The last committed leader round only advances, so it is updated from a single task.
+        let cache_clone = cache.clone();
+        let round_updates = tokio::spawn(async move {
+            for round in 1..=MAX_ROUND {
+                cache_clone.update_last_committed_leader_round(round);
+                tokio::task::yield_now().await;
+            }
+        });
+        let rejections: Vec<_> = (0..NUM_REJECTIONS)
+            .map(|i| {
+                let cache_clone = cache.clone();
+                tokio::spawn(async move {
+                    cache_clone.set_transaction_status(
+                        create_test_tx_position(i % MAX_ROUND, i / MAX_ROUND),
+                        ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+                    );
+                })
+            })
+            .collect();
+        for handle in rejections {
+            handle.await.unwrap();
+        }
+        round_updates.await.unwrap();
+
+        // No position out of the retention window is left after the round advanced.
+        for position in cache.snapshot() {
+            assert!(position.block.round as u64 + RETENTION_ROUNDS >= MAX_ROUND);
+            assert_eq!(
+                cache.rejection_reason(&position),
+                Some(ConsensusRejectionReason::FastPath)
+            );
+        }
+        // Each round has 2 positions.
+        assert!(cache.len() as u64 <= 2 * (RETENTION_ROUNDS + 1));
+        // Rejections reported after their round expired are not counted.
+        let num_counted = cache
+            .metrics
+            .consensus_tx_rejections
+            .with_label_values(&["fastpath"])
+            .get();
+        assert!(num_counted <= NUM_REJECTIONS);
+        assert!(num_counted >= cache.len() as u64);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_stall_alerts() {
+        let metrics = test_metrics();
+        let config = ConsensusTxStatusCacheConfig {
+            stall_alert_timeout: Duration::from_secs(1),
+            ..Default::default()
+        };
+        let cache = ConsensusTxStatusCache::new(0, config, metrics.clone());
+
+        // No alert before the first committed round.
+        tokio::time::sleep(Duration::from_millis(2500)).await;
+        assert_eq!(metrics.consensus_stall_alerts.get(), 0);
+
+        // An alert for every timeout without a new committed round.
+        cache.update_last_committed_leader_round(1);
+        tokio::time::sleep(Duration::from_millis(2500)).await;
+        assert_eq!(metrics.consensus_stall_alerts.get(), 2);
+
+        // Committed rounds reset the timeout.
+        for round in 2..10 {
+            cache.update_last_committed_leader_round(round);
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+        assert_eq!(metrics.consensus_stall_alerts.get(), 2);
     }
 }
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use mysten_network::config::Config;
 use std::collections::BTreeMap;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
 use sui_network::{api::ValidatorClient, tonic};
 use sui_types::base_types::AuthorityName;
@@ -22,8 +23,11 @@ use sui_types::{
     transaction::*,
 };
 use tap::TapFallible;
+use tokio::time::sleep;
+use tracing::debug;
 
 use crate::authority_client::tonic::IntoRequest;
+use crate::wait_for_effects_request::{WaitForEffectsRequest, WaitForEffectsResponse};
 use sui_network::tonic::metadata::KeyAndValueRef;
 use sui_network::tonic::transport::Channel;
 use sui_types::messages_grpc::{
@@ -72,6 +76,8 @@ pub trait AuthorityAPI {
 
     /// Wait for effects of a transaction that has been submitted to the network
     /// through the `submit_transaction` API.
+    /// This is a validator RPC: it is not served by fullnode JSON-RPC, so SDK clients
+    /// cannot call it.
     async fn wait_for_effects(
         &self,
         request: RawWaitForEffectsRequest,
@@ -322,6 +328,96 @@ impl AuthorityAPI for NetworkAuthorityClient {
     }
 }
 
+/// Controls how `WaitForEffectsClient` retries failed `wait_for_effects` calls.
+#[derive(Clone, Debug)]
+pub struct WaitForEffectsRetryConfig {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry. The delay doubles after each retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for WaitForEffectsRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Wraps an authority client to call `wait_for_effects` with typed requests and responses,
+/// retrying transport errors with exponential backoff.
+///
+/// Responses that fail to deserialize are not retried. The response is received intact over
+/// gRPC, so a parse error means the peer sent an incompatible or malformed response, and asking
+/// again would return the same result.
+///
+/// No connection pooling is done here. NetworkAuthorityClient holds a tonic channel that
+/// multiplexes concurrent requests over one HTTP/2 connection per validator, and keep-alive
+/// settings are configured when the channel is created, through
+/// `make_network_authority_clients_with_network_config`.
+pub struct WaitForEffectsClient<A> {
+    client: Arc<A>,
+    config: WaitForEffectsRetryConfig,
+}
+
+impl<A: AuthorityAPI + Send + Sync> WaitForEffectsClient<A> {
+    pub fn new(client: Arc<A>, config: WaitForEffectsRetryConfig) -> Self {
+        Self { client, config }
+    }
+
+    pub async fn wait_for_effects(
+        &self,
+        request: WaitForEffectsRequest,
+        client_addr: Option<SocketAddr>,
+    ) -> SuiResult<WaitForEffectsResponse> {
+        let digest = request.transaction_digest;
+        let raw_request = RawWaitForEffectsRequest::try_from(request)?;
+        let mut attempt = 0;
+        loop {
+            let result = self
+                .client
+                .wait_for_effects(raw_request.clone(), client_addr)
+                .await
+                .and_then(WaitForEffectsResponse::try_from);
+            match result {
+                Err(err)
+                    if attempt < self.config.max_retries && is_retryable_transport_error(&err) =>
+                {
+                    let backoff = self.backoff(attempt);
+                    debug!(
+                        ?digest,
+                        attempt, "wait_for_effects failed, retrying in {:?}: {}", backoff, err
+                    );
+                    sleep(backoff).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.config
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.config.max_backoff)
+    }
+}
+
+/// Returns true if a `wait_for_effects` error may succeed when the call is retried.
+fn is_retryable_transport_error(err: &SuiError) -> bool {
+    match err {
+        SuiError::GrpcMessageSerializeError { .. }
+        | SuiError::GrpcMessageDeserializeError { .. } => false,
+        _ => err.is_retryable().0,
+    }
+}
+
 pub fn make_network_authority_clients_with_network_config(
     committee: &CommitteeWithNetworkMetadata,
     network_config: &Config,
@@ -390,3 +486,23 @@ fn insert_metadata<T>(request: &mut tonic::Request<T>, client_addr: Option<Socke
             });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wait_for_effects_retryable_errors() {
+        assert!(is_retryable_transport_error(&SuiError::RpcError(
+            "connection reset".to_string(),
+            "Unavailable".to_string(),
+        )));
+        assert!(!is_retryable_transport_error(
+            &SuiError::GrpcMessageDeserializeError {
+                type_info: "RawWaitForEffectsResponse".to_string(),
+                error: "unexpected end of input".to_string(),
+            }
+        ));
+        assert!(!is_retryable_transport_error(&SuiError::TooManyRequests));
+    }
+}
@@ -5,7 +5,7 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use fastcrypto::traits::KeyPair;
-use futures::TryFutureExt;
+use futures::{stream::BoxStream, TryFutureExt, TryStreamExt};
 use mysten_metrics::spawn_monitored_task;
 use mysten_network::server::SUI_TLS_SERVER_NAME;
 use prometheus::{
@@ -29,7 +29,7 @@ use sui_types::{
     effects::TransactionEffects,
     messages_grpc::{
         HandleCertificateRequestV3, HandleCertificateResponseV3, RawSubmitTxResponse,
-        RawWaitForEffectsRequest, RawWaitForEffectsResponse,
+        RawWaitForEffectsRequest, RawWaitForEffectsResponse, RawWaitForEffectsResponseChunk,
     },
 };
 use sui_types::{effects::TransactionEffectsAPI, messages_grpc::SubmitTxResponse};
@@ -73,7 +73,10 @@ use crate::{
     mysticeti_adapter::LazyMysticetiClient,
     transaction_outputs::TransactionOutputs,
     wait_for_effects_request::{
-        ExecutedData, RejectReason, WaitForEffectsRequest, WaitForEffectsResponse,
+        response_chunk_stream, ExecutedData, RejectReason, WaitForEffectsDispatcher,
+        WaitForEffectsRequest, WaitForEffectsResponse, WaitForEffectsResponseCache,
+        WaitForEffectsResponseCacheKey, OBJECTS_PER_RESPONSE_CHUNK,
+        WAIT_FOR_EFFECTS_RESPONSE_CACHE_SIZE_BYTES,
     },
 };
 use crate::{
@@ -394,6 +397,8 @@ pub struct ValidatorService {
     metrics: Arc<ValidatorServiceMetrics>,
     traffic_controller: Option<Arc<TrafficController>>,
     client_id_source: Option<ClientIdSource>,
+    wait_for_effects_response_cache: Arc<WaitForEffectsResponseCache>,
+    wait_for_effects_dispatcher: Arc<WaitForEffectsDispatcher>,
 }
 
 impl ValidatorService {
@@ -417,6 +422,10 @@ impl ValidatorService {
                 ))
             }),
             client_id_source: policy_config.map(|policy| policy.client_id_source),
+            wait_for_effects_response_cache: Arc::new(WaitForEffectsResponseCache::new(
+                WAIT_FOR_EFFECTS_RESPONSE_CACHE_SIZE_BYTES,
+            )),
+            wait_for_effects_dispatcher: Arc::new(WaitForEffectsDispatcher::new()),
         }
     }
 
@@ -431,6 +440,10 @@ impl ValidatorService {
             metrics,
             traffic_controller: None,
             client_id_source: None,
+            wait_for_effects_response_cache: Arc::new(WaitForEffectsResponseCache::new(
+                WAIT_FOR_EFFECTS_RESPONSE_CACHE_SIZE_BYTES,
+            )),
+            wait_for_effects_dispatcher: Arc::new(WaitForEffectsDispatcher::new()),
         }
     }
 
@@ -466,6 +479,8 @@ impl ValidatorService {
             metrics,
             traffic_controller: _,
             client_id_source: _,
+            wait_for_effects_response_cache: _,
+            wait_for_effects_dispatcher: _,
         } = self.clone();
         let transaction = request.into_inner();
         let epoch_store = state.load_epoch_store_one_call_per_task();
@@ -541,6 +556,8 @@ impl ValidatorService {
             metrics,
             traffic_controller: _,
             client_id_source: _,
+            wait_for_effects_response_cache: _,
+            wait_for_effects_dispatcher: _,
         } = self.clone();
         let epoch_store = state.load_epoch_store_one_call_per_task();
         if !epoch_store.protocol_config().mysticeti_fastpath() {
@@ -968,6 +985,17 @@ impl ValidatorService {
 
 type WrappedServiceResponse<T> = Result<(tonic::Response<T>, Weight), tonic::Status>;
 
+pub type WaitForEffectsResponseStream =
+    BoxStream<'static, Result<RawWaitForEffectsResponseChunk, tonic::Status>>;
+
+/// The outcome of waiting for a transaction at the position of a WaitForEffects request.
+enum WaitForEffectsOutcome {
+    /// The transaction is executed. Fastpath outputs are provided if it is not finalized yet.
+    Executed(TransactionEffects, Option<Arc<TransactionOutputs>>),
+    /// The transaction will not be executed at the position, e.g. because it was rejected.
+    NotExecuted(WaitForEffectsResponse),
+}
+
 impl ValidatorService {
     async fn transaction_impl(
         &self,
@@ -1082,22 +1110,31 @@ impl ValidatorService {
         })
     }
 
+    /// Serves the WaitForEffects RPC of the validator service. The response is built from the
+    /// consensus transaction status cache and the execution caches of the authority.
     async fn wait_for_effects_impl(
         &self,
         request: tonic::Request<RawWaitForEffectsRequest>,
     ) -> WrappedServiceResponse<RawWaitForEffectsResponse> {
         let request: WaitForEffectsRequest = request.into_inner().try_into()?;
+        request.validate()?;
         let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        // Identical requests in flight share a single wait for the response.
         let response = timeout(
             // TODO(fastpath): Tune this once we have a good estimate of the typical delay.
             Duration::from_secs(20),
-            epoch_store
-                .within_alive_epoch(self.wait_for_effects_response(request, &epoch_store))
-                .map_err(|_| SuiError::EpochEnded(epoch_store.epoch())),
+            self.wait_for_effects_dispatcher
+                .dispatch(&request, || async {
+                    epoch_store
+                        .within_alive_epoch(
+                            self.wait_for_effects_response(request.clone(), &epoch_store),
+                        )
+                        .await
+                        .map_err(|_| SuiError::EpochEnded(epoch_store.epoch()))?
+                }),
         )
         .await
-        .map_err(|_| tonic::Status::internal("Timeout waiting for effects"))???
-        .try_into()?;
+        .map_err(|_| tonic::Status::internal("Timeout waiting for effects"))??;
         Ok((
             tonic::Response::new(response),
             // TODO(fastpath): Implement spam weight
@@ -1105,30 +1142,110 @@ impl ValidatorService {
         ))
     }
 
-    // TODO(fastpath): Add metrics.
+    /// Serves the streamed WaitForEffects RPC. Object chunks are encoded as they are sent, so
+    /// unlike `wait_for_effects_impl`, responses are not shared between requests or cached.
+    async fn wait_for_effects_stream_impl(
+        &self,
+        request: tonic::Request<RawWaitForEffectsRequest>,
+    ) -> WrappedServiceResponse<WaitForEffectsResponseStream> {
+        let request: WaitForEffectsRequest = request.into_inner().try_into()?;
+        request.validate()?;
+        let epoch_store = self.state.load_epoch_store_one_call_per_task();
+        let response = timeout(
+            // TODO(fastpath): Tune this once we have a good estimate of the typical delay.
+            Duration::from_secs(20),
+            epoch_store.within_alive_epoch(async {
+                match self
+                    .wait_for_effects_outcome(&request, &epoch_store)
+                    .await?
+                {
+                    WaitForEffectsOutcome::Executed(effects, fastpath_outputs) => {
+                        self.build_executed_response(
+                            &request,
+                            effects,
+                            fastpath_outputs,
+                            &epoch_store,
+                        )
+                        .await
+                    }
+                    WaitForEffectsOutcome::NotExecuted(response) => Ok(response),
+                }
+            }),
+        )
+        .await
+        .map_err(|_| tonic::Status::internal("Timeout waiting for effects"))?
+        .map_err(|_| SuiError::EpochEnded(epoch_store.epoch()))??;
+        // Effects and events are sent ahead of the objects, which can make up most of the
+        // response for transactions touching many objects.
+        let stream: WaitForEffectsResponseStream = Box::pin(
+            response_chunk_stream(response, OBJECTS_PER_RESPONSE_CHUNK)?
+                .map_err(tonic::Status::from),
+        );
+        Ok((
+            tonic::Response::new(stream),
+            // TODO(fastpath): Implement spam weight
+            Weight::zero(),
+        ))
+    }
+
     async fn wait_for_effects_response(
         &self,
         request: WaitForEffectsRequest,
         epoch_store: &Arc<AuthorityPerEpochStore>,
-    ) -> SuiResult<WaitForEffectsResponse> {
-        let Some(consensus_tx_status_cache) = epoch_store.consensus_tx_status_cache.as_ref() else {
-            return Err(SuiError::UnsupportedFeatureError {
-                error: "Mysticeti fastpath".to_string(),
-            });
-        };
+    ) -> SuiResult<RawWaitForEffectsResponse> {
+        match self.wait_for_effects_outcome(&request, epoch_store).await? {
+            WaitForEffectsOutcome::Executed(effects, fastpath_outputs) => {
+                self.executed_response(&request, effects, fastpath_outputs, epoch_store)
+                    .await
+            }
+            WaitForEffectsOutcome::NotExecuted(response) => response.try_into(),
+        }
+    }
+
+    // TODO(fastpath): Add metrics.
+    async fn wait_for_effects_outcome(
+        &self,
+        request: &WaitForEffectsRequest,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
+    ) -> SuiResult<WaitForEffectsOutcome> {
         if request.epoch != epoch_store.epoch() {
             return Err(SuiError::WrongEpoch {
                 expected_epoch: epoch_store.epoch(),
                 actual_epoch: request.epoch,
             });
         }
-        consensus_tx_status_cache.check_position_too_ahead(&request.transaction_position)?;
+        let Some(transaction_position) = request.transaction_position else {
+            // Without a consensus position, the transaction is not tracked by consensus status,
+            // so only wait for it to be executed.
+            let transactions = [request.transaction_digest];
+            let mut effects = self
+                .state
+                .get_transaction_cache_reader()
+                .notify_read_executed_effects(&transactions)
+                .await;
+            debug!(
+                tx_digest = ?request.transaction_digest,
+                "Observed executed effects",
+            );
+            // unwrap is safe because notify_read_executed_effects is expected
+            // to return the same amount of effects as the provided transactions.
+            return Ok(WaitForEffectsOutcome::Executed(
+                effects.pop().unwrap(),
+                None,
+            ));
+        };
+        let Some(consensus_tx_status_cache) = epoch_store.consensus_tx_status_cache.as_ref() else {
+            return Err(SuiError::UnsupportedFeatureError {
+                error: "Mysticeti fastpath".to_string(),
+            });
+        };
+        consensus_tx_status_cache.check_position_too_ahead(&transaction_position)?;
 
         // Because we need to associate effects with a specific transaction position,
         // we need to first make sure that this specific position is accepted by consensus,
         // either with fastpath certified or post-commit finalized.
         let first_status = consensus_tx_status_cache
-            .notify_read_transaction_status(request.transaction_position, None)
+            .notify_read_transaction_status(transaction_position, None)
             .await;
         debug!(
             tx_digest = ?request.transaction_digest,
@@ -1137,17 +1254,28 @@ impl ValidatorService {
         );
         let mut cur_status = match first_status {
             NotifyReadConsensusTxStatusResult::Status(status) => match status {
-                ConsensusTxStatus::Rejected => {
+                ConsensusTxStatus::Rejected(rejection_reason) => {
+                    debug!(
+                        tx_digest = ?request.transaction_digest,
+                        ?rejection_reason,
+                        "Transaction rejected by consensus",
+                    );
                     let response = WaitForEffectsResponse::Rejected {
                         // TODO(fastpath): Add reject reason.
                         reason: RejectReason::None,
+                        consensus_code: Some(rejection_reason.into()),
                     };
-                    return Ok(response);
+                    return Ok(WaitForEffectsOutcome::NotExecuted(response));
                 }
                 ConsensusTxStatus::FastpathCertified | ConsensusTxStatus::Finalized => status,
             },
             NotifyReadConsensusTxStatusResult::Expired(round) => {
-                return Ok(WaitForEffectsResponse::Expired(round));
+                return Ok(WaitForEffectsOutcome::NotExecuted(
+                    WaitForEffectsResponse::Expired(round),
+                ));
+            }
+            NotifyReadConsensusTxStatusResult::Cleared => {
+                return Err(SuiError::EpochEnded(epoch_store.epoch()));
             }
         };
         // Now that we know the transaction position is accepted by consensus,
@@ -1158,7 +1286,7 @@ impl ValidatorService {
         let (effects, fastpath_outputs) = loop {
             let transactions = [request.transaction_digest];
             tokio::select! {
-                second_status = consensus_tx_status_cache.notify_read_transaction_status(request.transaction_position, Some(cur_status)) => {
+                second_status = consensus_tx_status_cache.notify_read_transaction_status(transaction_position, Some(cur_status)) => {
                     debug!(
                         tx_digest = ?request.transaction_digest,
                         "Observed consensus transaction status: {:?}",
@@ -1166,8 +1294,18 @@ impl ValidatorService {
                     );
                     match second_status {
                         NotifyReadConsensusTxStatusResult::Status(status) => {
-                            if status == ConsensusTxStatus::Rejected {
-                                return Ok(WaitForEffectsResponse::Rejected { reason: RejectReason::None });
+                            if let ConsensusTxStatus::Rejected(rejection_reason) = status {
+                                debug!(
+                                    tx_digest = ?request.transaction_digest,
+                                    ?rejection_reason,
+                                    "Transaction rejected by consensus after fastpath certification",
+                                );
+                                return Ok(WaitForEffectsOutcome::NotExecuted(
+                                    WaitForEffectsResponse::Rejected {
+                                        reason: RejectReason::None,
+                                        consensus_code: Some(rejection_reason.into()),
+                                    },
+                                ));
                             }
                             assert!(matches!(status, ConsensusTxStatus::Finalized));
                             // Update the current status so that notify_read_transaction_status will no
@@ -1176,7 +1314,10 @@ impl ValidatorService {
                             continue;
                         }
                         NotifyReadConsensusTxStatusResult::Expired(round) => {
-                            return Ok(WaitForEffectsResponse::Expired(round));
+                            return Ok(WaitForEffectsOutcome::NotExecuted(WaitForEffectsResponse::Expired(round)));
+                        }
+                        NotifyReadConsensusTxStatusResult::Cleared => {
+                            return Err(SuiError::EpochEnded(epoch_store.epoch()));
                         }
                     }
                 },
@@ -1196,13 +1337,97 @@ impl ValidatorService {
                         tx_digest = ?request.transaction_digest,
                         "Observed fastpath transaction outputs",
                     );
+                    // The transaction can be rejected post commit right before its fastpath
+                    // outputs become available. Outputs of a rejected transaction must not be returned.
+                    if let Some(rejection_reason) =
+                        consensus_tx_status_cache.rejection_reason(&transaction_position)
+                    {
+                        return Ok(WaitForEffectsOutcome::NotExecuted(
+                            WaitForEffectsResponse::Rejected {
+                                reason: RejectReason::None,
+                                consensus_code: Some(rejection_reason.into()),
+                            },
+                        ));
+                    }
                     let outputs = outputs.pop().unwrap();
                     break (outputs.effects.clone(), Some(outputs));
                 }
             }
         };
+        Ok(WaitForEffectsOutcome::Executed(effects, fastpath_outputs))
+    }
+
+    async fn executed_response(
+        &self,
+        request: &WaitForEffectsRequest,
+        effects: TransactionEffects,
+        fastpath_outputs: Option<Arc<TransactionOutputs>>,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
+    ) -> SuiResult<RawWaitForEffectsResponse> {
+        // Executed effects are final, so their encoded response can be shared with other
+        // requests for the same transaction. Fastpath outputs can still be rejected post commit.
+        let cache_key = fastpath_outputs
+            .is_none()
+            .then(|| WaitForEffectsResponseCacheKey::from(request));
+        if let Some(response) = cache_key
+            .as_ref()
+            .and_then(|key| self.wait_for_effects_response_cache.get(key))
+        {
+            return Ok(response);
+        }
+        let response = self
+            .build_executed_response(request, effects, fastpath_outputs, epoch_store)
+            .await?;
+        // The signature may be persisted later, so do not cache the response without it.
+        let unsigned = matches!(
+            &response,
+            WaitForEffectsResponse::Executed {
+                details: Some(details),
+                ..
+            } if details.signature.is_none()
+        );
+        let response: RawWaitForEffectsResponse = response.try_into()?;
+        if let Some(key) = cache_key.filter(|_| !unsigned) {
+            self.wait_for_effects_response_cache
+                .insert(key, response.clone());
+        }
+        Ok(response)
+    }
+
+    async fn build_executed_response(
+        &self,
+        request: &WaitForEffectsRequest,
+        effects: TransactionEffects,
+        fastpath_outputs: Option<Arc<TransactionOutputs>>,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
+    ) -> SuiResult<WaitForEffectsResponse> {
         let effects_digest = effects.digest();
+        let gas_cost_summary = request
+            .include_gas_summary
+            .then(|| effects.gas_cost_summary().clone());
         let details = if request.include_details {
+            let auxiliary_objects = if request.include_auxiliary_data {
+                let ((gas_object_id, gas_object_version, _), _) = effects.gas_object();
+                let gas_object = match &fastpath_outputs {
+                    Some(outputs) => outputs.written.get(&gas_object_id).cloned(),
+                    None => self
+                        .state
+                        .get_object_cache_reader()
+                        .get_object_by_key(&gas_object_id, gas_object_version),
+                };
+                // System transactions do not have a gas object.
+                gas_object.into_iter().collect()
+            } else {
+                vec![]
+            };
+            // Only return a signature this validator has already persisted for the effects.
+            // Effects are never signed here: fastpath outputs can still be rejected post
+            // commit, and signing would be a DB write and a BLS signature per read request.
+            let signature = if fastpath_outputs.is_none() {
+                epoch_store.get_effects_signature(effects.transaction_digest())?
+            } else {
+                None
+            };
             let (events, input_objects, output_objects) = self
                 .collect_effects_data(
                     &effects,
@@ -1217,15 +1442,17 @@ impl ValidatorService {
                 events,
                 input_objects,
                 output_objects,
+                auxiliary_objects,
+                signature,
             }))
         } else {
             None
         };
-        let response = WaitForEffectsResponse::Executed {
+        Ok(WaitForEffectsResponse::Executed {
             effects_digest,
             details,
-        };
-        Ok(response)
+            gas_cost_summary,
+        })
     }
 
     async fn soft_bundle_validity_check(
@@ -1723,6 +1950,15 @@ impl Validator for ValidatorService {
         handle_with_decoration!(self, wait_for_effects_impl, request)
     }
 
+    type WaitForEffectsStreamStream = WaitForEffectsResponseStream;
+
+    async fn wait_for_effects_stream(
+        &self,
+        request: tonic::Request<RawWaitForEffectsRequest>,
+    ) -> Result<tonic::Response<Self::WaitForEffectsStreamStream>, tonic::Status> {
+        handle_with_decoration!(self, wait_for_effects_stream_impl, request)
+    }
+
     async fn handle_soft_bundle_certificates_v3(
         &self,
         request: tonic::Request<HandleSoftBundleCertificatesRequestV3>,
@@ -47,7 +47,7 @@ use crate::{
             ExecutionIndicesWithStats,
         },
         backpressure::{BackpressureManager, BackpressureSubscriber},
-        consensus_tx_status_cache::ConsensusTxStatus,
+        consensus_tx_status_cache::{ConsensusRejectionReason, ConsensusTxStatus},
         epoch_start_configuration::EpochStartConfigTrait,
         AuthorityMetrics, AuthorityState,
     },
@@ -579,9 +579,14 @@ impl<C: CheckpointServiceNotify + Send + Sync> ConsensusHandler<C> {
 
         if let Some(consensus_tx_status_cache) = self.epoch_store.consensus_tx_status_cache.as_ref()
         {
-            consensus_tx_status_cache
-                .update_last_committed_leader_round(last_committed_round)
-                .await;
+            let num_evicted =
+                consensus_tx_status_cache.update_last_committed_leader_round(last_committed_round);
+            debug!(
+                "Evicted {} expired consensus transaction statuses at last committed round {}, {} remaining",
+                num_evicted,
+                last_committed_round,
+                consensus_tx_status_cache.len()
+            );
         }
 
         let commit_info = if self
@@ -713,28 +718,27 @@ impl<C: CheckpointServiceNotify + Send + Sync> ConsensusHandler<C> {
         {
             let span = trace_span!("ConsensusHandler::HandleCommit::process_consensus_txns");
             let _guard = span.enter();
+            let mut tx_status_updates = vec![];
             for (block, parsed_transactions) in consensus_commit.transactions() {
                 let author = block.author.value();
                 // TODO: consider only messages within 1~3 rounds of the leader?
                 self.last_consensus_stats.stats.inc_num_messages(author);
                 for (tx_index, parsed) in parsed_transactions.into_iter().enumerate() {
-                    let position = ConsensusTxPosition {
-                        block,
-                        index: tx_index as TransactionIndex,
-                    };
+                    let position = ConsensusTxPosition::new(block, tx_index as TransactionIndex);
                     if parsed.rejected {
-                        // TODO(fastpath): Add metrics for rejected transactions.
+                        // Rejections are counted by stage in the status cache.
                         if parsed.transaction.kind.is_user_transaction() {
-                            self.epoch_store
-                                .set_consensus_tx_status(position, ConsensusTxStatus::Rejected);
+                            tx_status_updates.push((
+                                position,
+                                ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+                            ));
                         }
                         // Skip executing rejected transactions.
                         // TODO(fastpath): Handle unlocking.
                         continue;
                     }
                     if parsed.transaction.kind.is_user_transaction() {
-                        self.epoch_store
-                            .set_consensus_tx_status(position, ConsensusTxStatus::Finalized);
+                        tx_status_updates.push((position, ConsensusTxStatus::Finalized));
                     }
                     let kind = classify(&parsed.transaction);
                     self.metrics
@@ -771,6 +775,8 @@ impl<C: CheckpointServiceNotify + Send + Sync> ConsensusHandler<C> {
                     }
                 }
             }
+            self.epoch_store
+                .set_consensus_tx_statuses(tx_status_updates);
         }
 
         for (i, authority) in self.committee.authorities() {
@@ -1273,24 +1279,23 @@ impl ConsensusBlockHandler {
             })
             .collect::<Vec<_>>();
         let mut executable_transactions = vec![];
+        let mut tx_status_updates = vec![];
         for (idx, (block, transactions)) in parsed_transactions.into_iter().enumerate() {
             for parsed in transactions {
-                let position = ConsensusTxPosition {
-                    block,
-                    index: idx as TransactionIndex,
-                };
+                let position = ConsensusTxPosition::new(block, idx as TransactionIndex);
                 if parsed.rejected {
                     // TODO(fastpath): avoid parsing blocks twice between handling commit and fastpath transactions?
-                    self.epoch_store
-                        .set_consensus_tx_status(position, ConsensusTxStatus::Rejected);
+                    tx_status_updates.push((
+                        position,
+                        ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+                    ));
                     self.metrics
                         .consensus_block_handler_txn_processed
                         .with_label_values(&["rejected"])
                         .inc();
                     continue;
                 }
-                self.epoch_store
-                    .set_consensus_tx_status(position, ConsensusTxStatus::FastpathCertified);
+                tx_status_updates.push((position, ConsensusTxStatus::FastpathCertified));
 
                 self.metrics
                     .consensus_block_handler_txn_processed
@@ -1311,6 +1316,8 @@ impl ConsensusBlockHandler {
                 }
             }
         }
+        self.epoch_store
+            .set_consensus_tx_statuses(tx_status_updates);
 
         if executable_transactions.is_empty() {
             return;
@@ -2,12 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use prometheus::{
-    register_counter_vec_with_registry, register_int_counter_vec_with_registry,
-    register_int_counter_with_registry, register_int_gauge_with_registry, CounterVec, IntCounter,
-    IntCounterVec, IntGauge, Registry,
+    register_counter_vec_with_registry, register_histogram_with_registry,
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, CounterVec, Histogram, IntCounter, IntCounterVec, IntGauge,
+    Registry,
 };
 use std::sync::Arc;
 
+const REJECTIONS_PER_ROUND_BUCKETS: &[f64] = &[
+    0., 1., 2., 5., 10., 20., 50., 100., 200., 500., 1000., 2000., 5000., 10000.,
+];
+
 pub struct EpochMetrics {
     /// The current epoch ID. This is updated only when the AuthorityState finishes reconfiguration.
     pub current_epoch: IntGauge,
@@ -139,6 +144,51 @@ pub struct EpochMetrics {
 
     /// The number of shared object assignments in the quarantine.
     pub shared_object_assignments_size: IntGauge,
+
+    /// The number of consensus transaction status updates applied to the status cache, by status.
+    pub consensus_tx_status_updates: IntCounterVec,
+
+    /// The number of transactions rejected in the status cache, by rejection stage (fastpath or
+    /// post_commit). Only the first rejection of a transaction is counted, so a post-commit
+    /// rejection of a transaction already rejected in the fastpath is not.
+    pub consensus_tx_rejections: IntCounterVec,
+
+    /// The number of transactions rejected in the status cache, by the authority index of the
+    /// block proposing them. A high share of rejections from one authority can point to a
+    /// misbehaving validator.
+    pub consensus_tx_rejections_by_authority: IntCounterVec,
+
+    /// The number of consensus transaction statuses garbage collected from the status cache
+    /// after falling out of the retention window.
+    pub consensus_tx_status_expired: IntCounter,
+
+    /// The number of waits on consensus transaction status completed, by result.
+    pub consensus_tx_status_notify_read: IntCounterVec,
+
+    /// The number of consensus transaction statuses currently held in the status cache.
+    pub consensus_tx_status_cache_size: IntGauge,
+
+    /// The number of rounds tracked for expiration in the consensus transaction status cache.
+    /// Expired rounds are removed whenever the last committed leader round advances, so this stays
+    /// close to the retention window. Alert when it exceeds twice the number of retention rounds
+    /// (800 by default), which indicates that the last committed round is not being updated.
+    pub consensus_tx_status_round_map_entries: IntGauge,
+
+    /// The number of last committed leader round updates seen by the status cache.
+    /// Its rate shows consensus progress independently of transaction volume.
+    pub consensus_tx_status_committed_rounds_seen: IntCounter,
+
+    /// The last committed leader round seen by the status cache.
+    pub consensus_tx_status_last_committed_round: IntGauge,
+
+    /// The number of times no leader round was committed within the stall alert timeout of
+    /// the status cache, which suggests that consensus is stalled.
+    pub consensus_stall_alerts: IntCounter,
+
+    /// The number of rejected transactions in each consensus round with tracked statuses,
+    /// observed by the status cache when the round leaves the retention window. Spikes can
+    /// indicate spam.
+    pub consensus_tx_rejections_per_round: Histogram,
 }
 
 impl EpochMetrics {
@@ -326,6 +376,77 @@ impl EpochMetrics {
                 registry
             )
             .unwrap(),
+            consensus_tx_status_updates: register_int_counter_vec_with_registry!(
+                "consensus_tx_status_updates",
+                "The number of consensus transaction status updates applied to the status cache, by status",
+                &["status"],
+                registry
+            )
+            .unwrap(),
+            consensus_tx_rejections: register_int_counter_vec_with_registry!(
+                "consensus_tx_rejections",
+                "The number of transaction rejections recorded in the status cache, by rejection stage",
+                &["stage"],
+                registry
+            )
+            .unwrap(),
+            consensus_tx_rejections_by_authority: register_int_counter_vec_with_registry!(
+                "consensus_tx_rejections_by_authority",
+                "The number of transactions rejected in the status cache, by the authority index of the proposing block",
+                &["authority_index"],
+                registry
+            )
+            .unwrap(),
+            consensus_tx_status_expired: register_int_counter_with_registry!(
+                "consensus_tx_status_expired",
+                "The number of consensus transaction statuses garbage collected from the status cache",
+                registry
+            )
+            .unwrap(),
+            consensus_tx_status_notify_read: register_int_counter_vec_with_registry!(
+                "consensus_tx_status_notify_read",
+                "The number of waits on consensus transaction status completed, by result",
+                &["result"],
+                registry
+            )
+            .unwrap(),
+            consensus_tx_status_cache_size: register_int_gauge_with_registry!(
+                "consensus_tx_status_cache_size",
+                "The number of consensus transaction statuses currently held in the status cache",
+                registry
+            )
+            .unwrap(),
+            consensus_tx_status_round_map_entries: register_int_gauge_with_registry!(
+                "consensus_tx_status_round_map_entries",
+                "The number of rounds tracked for expiration in the consensus transaction status cache",
+                registry
+            )
+            .unwrap(),
+            consensus_tx_status_committed_rounds_seen: register_int_counter_with_registry!(
+                "consensus_tx_status_committed_rounds_seen",
+                "The number of last committed leader round updates seen by the status cache",
+                registry
+            )
+            .unwrap(),
+            consensus_tx_status_last_committed_round: register_int_gauge_with_registry!(
+                "consensus_tx_status_last_committed_round",
+                "The last committed leader round seen by the status cache",
+                registry
+            )
+            .unwrap(),
+            consensus_stall_alerts: register_int_counter_with_registry!(
+                "consensus_stall_alerts",
+                "The number of times no leader round was committed within the stall alert timeout",
+                registry
+            )
+            .unwrap(),
+            consensus_tx_rejections_per_round: register_histogram_with_registry!(
+                "consensus_tx_rejections_per_round",
+                "The number of rejected transactions in each consensus round, observed when the round expires",
+                REJECTIONS_PER_ROUND_BUCKETS.to_vec(),
+                registry
+            )
+            .unwrap(),
         };
         Arc::new(this)
     }
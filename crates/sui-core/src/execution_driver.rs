@@ -8,11 +8,11 @@ use mysten_metrics::{monitored_scope, spawn_monitored_task};
 use rand::Rng;
 use sui_macros::fail_point_async;
 use sui_types::error::SuiError;
-use tokio::sync::{mpsc::UnboundedReceiver, oneshot, Semaphore};
+use tokio::sync::{oneshot, Semaphore};
 use tracing::{error_span, info, trace, warn, Instrument};
 
 use crate::authority::AuthorityState;
-use crate::execution_scheduler::PendingCertificate;
+use crate::execution_scheduler::ReadyCertificatesReceiver;
 
 #[cfg(test)]
 #[path = "unit_tests/execution_driver_tests.rs"]
@@ -24,7 +24,7 @@ const QUEUEING_DELAY_SAMPLING_RATIO: f64 = 0.05;
 /// processing the transaction in a loop.
 pub async fn execution_process(
     authority_state: Weak<AuthorityState>,
-    mut rx_ready_certificates: UnboundedReceiver<PendingCertificate>,
+    mut rx_ready_certificates: ReadyCertificatesReceiver,
     mut rx_execution_shutdown: oneshot::Receiver<()>,
 ) {
     info!("Starting pending certificates execution process.");
@@ -6,26 +6,34 @@ use crate::{
     execution_cache::{ObjectCacheRead, TransactionCacheRead},
     execution_scheduler::{ExecutingGuard, PendingCertificateStats},
 };
-use mysten_metrics::spawn_monitored_task;
+use dashmap::DashSet;
+use mysten_common::{debug_fatal, sync::notify_once::NotifyOnce};
+use mysten_metrics::{spawn_monitored_task, GaugeGuard};
 use std::{
     collections::{BTreeSet, HashSet},
-    sync::Arc,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
 use sui_config::node::AuthorityOverloadConfig;
 use sui_types::{
     base_types::FullObjectID,
-    digests::TransactionEffectsDigest,
+    digests::{TransactionDigest, TransactionEffectsDigest},
     error::SuiResult,
     executable_transaction::VerifiedExecutableTransaction,
     storage::InputKey,
     transaction::{SenderSignedData, TransactionDataAPI},
 };
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::watch;
 use tokio::time::Instant;
-use tracing::{debug, warn};
+use tracing::{debug, debug_span, info_span, warn, Instrument};
 
 use super::{
-    overload_tracker::OverloadTracker, ExecutionSchedulerAPI, PendingCertificate, SchedulingSource,
+    overload_tracker::OverloadTracker, ExecutionSchedulerAPI, PendingCertificate,
+    ReadyCertificatesSender, SchedulingSource, TransactionPriority,
 };
 
 #[derive(Clone)]
@@ -33,10 +41,59 @@ pub(crate) struct ExecutionScheduler {
     object_cache_read: Arc<dyn ObjectCacheRead>,
     transaction_cache_read: Arc<dyn TransactionCacheRead>,
     overload_tracker: Arc<OverloadTracker>,
-    tx_ready_certificates: UnboundedSender<PendingCertificate>,
+    tx_ready_certificates: ReadyCertificatesSender,
+    // How long a transaction waits for its input objects before they are logged.
+    input_objects_wait_warn_threshold: Duration,
+    // Set when the scheduler stops accepting new transactions.
+    shutting_down: Arc<AtomicBool>,
+    // Notified when the scheduler is aborted, to stop waiting for input objects.
+    abort_notify: Arc<NotifyOnce>,
+    // Number of scheduling tasks that have been spawned and have not exited yet.
+    pending_count: Arc<watch::Sender<usize>>,
+    // Transactions with an enqueued scheduling task that has not exited yet, by scheduling
+    // source. Used to skip duplicate enqueues, e.g. from client retries.
+    in_flight: Arc<DashSet<(TransactionDigest, SchedulingSource)>>,
     metrics: Arc<AuthorityMetrics>,
 }
 
+/// Counts a scheduling task as pending until it exits, however it exits.
+struct PendingCountGuard(Arc<watch::Sender<usize>>);
+
+impl PendingCountGuard {
+    fn new(pending_count: Arc<watch::Sender<usize>>) -> Self {
+        pending_count.send_modify(|count| *count += 1);
+        Self(pending_count)
+    }
+}
+
+impl Drop for PendingCountGuard {
+    fn drop(&mut self) {
+        self.0.send_modify(|count| *count -= 1);
+    }
+}
+
+/// Marks a transaction as in flight until its scheduling task exits.
+struct InFlightGuard {
+    in_flight: Arc<DashSet<(TransactionDigest, SchedulingSource)>>,
+    key: (TransactionDigest, SchedulingSource),
+}
+
+impl InFlightGuard {
+    // Returns None if the transaction is already in flight.
+    fn try_new(
+        in_flight: Arc<DashSet<(TransactionDigest, SchedulingSource)>>,
+        key: (TransactionDigest, SchedulingSource),
+    ) -> Option<Self> {
+        in_flight.insert(key).then_some(Self { in_flight, key })
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.in_flight.remove(&self.key);
+    }
+}
+
 struct PendingGuard<'a> {
     scheduler: &'a ExecutionScheduler,
     cert: &'a VerifiedExecutableTransaction,
@@ -71,7 +128,8 @@ impl ExecutionScheduler {
     pub fn new(
         object_cache_read: Arc<dyn ObjectCacheRead>,
         transaction_cache_read: Arc<dyn TransactionCacheRead>,
-        tx_ready_certificates: UnboundedSender<PendingCertificate>,
+        tx_ready_certificates: ReadyCertificatesSender,
+        overload_config: &AuthorityOverloadConfig,
         metrics: Arc<AuthorityMetrics>,
     ) -> Self {
         tracing::info!("Creating new ExecutionScheduler");
@@ -80,10 +138,102 @@ impl ExecutionScheduler {
             transaction_cache_read,
             overload_tracker: Arc::new(OverloadTracker::new()),
             tx_ready_certificates,
+            input_objects_wait_warn_threshold: overload_config.input_objects_wait_warn_threshold,
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            abort_notify: Arc::new(NotifyOnce::new()),
+            pending_count: Arc::new(watch::channel(0).0),
+            in_flight: Arc::new(DashSet::new()),
             metrics,
         }
     }
 
+    /// Returns the number of transactions being scheduled, from the time their scheduling task
+    /// is spawned until it exits. This includes transactions waiting for their input objects, but
+    /// not transactions already sent for execution.
+    pub fn pending_count(&self) -> usize {
+        *self.pending_count.borrow()
+    }
+
+    /// Stops accepting new transactions. Returns a future that resolves once all transactions
+    /// already being scheduled are sent for execution, found to be executed, or aborted.
+    /// Transactions still waiting for input objects keep waiting, unless `abort()` is called.
+    pub fn shutdown(&self) -> impl Future<Output = ()> + Send + 'static {
+        if !self.shutting_down.swap(true, Ordering::Relaxed) {
+            tracing::info!("Shutting down ExecutionScheduler, draining scheduled transactions");
+        }
+        let pending_count = self.pending_count.clone();
+        async move {
+            pending_count
+                .subscribe()
+                .wait_for(|count| *count == 0)
+                .await
+                .expect("pending count sender is held by the scheduler");
+            tracing::info!("ExecutionScheduler drained");
+        }
+    }
+
+    /// Stops all transactions that are still waiting for their input objects.
+    /// Transactions enqueued after abort are dropped once they need to wait for inputs.
+    pub fn abort(&self) {
+        if self.abort_notify.notify().is_ok() {
+            tracing::info!("Aborting ExecutionScheduler");
+        }
+    }
+
+    fn spawn_schedule_transaction(
+        &self,
+        cert: VerifiedExecutableTransaction,
+        expected_effects_digest: Option<TransactionEffectsDigest>,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
+        scheduling_source: SchedulingSource,
+        skip_if_in_flight: bool,
+    ) {
+        let in_flight_guard = if skip_if_in_flight {
+            let key = (*cert.digest(), scheduling_source);
+            let Some(guard) = InFlightGuard::try_new(self.in_flight.clone(), key) else {
+                debug!(
+                    tx_digest = ?cert.digest(),
+                    "Transaction is already being scheduled, skipping duplicate enqueue"
+                );
+                self.metrics
+                    .transaction_manager_num_enqueued_certificates
+                    .with_label_values(&["duplicate"])
+                    .inc();
+                return;
+            };
+            Some(guard)
+        } else {
+            None
+        };
+        let scheduler = self.clone();
+        let epoch_store = epoch_store.clone();
+        // Created before spawning, so that the task is counted as soon as this returns.
+        let pending_count_guard = PendingCountGuard::new(self.pending_count.clone());
+        // Scheduling can wait for input objects for a long time. The span shows where
+        // that time is spent in traces, and identifies the transaction of a stuck task.
+        let span = info_span!(
+            "schedule_transaction",
+            tx_digest = ?cert.digest(),
+            epoch = epoch_store.epoch(),
+        );
+        spawn_monitored_task!(
+            async move {
+                let _pending_count_guard = pending_count_guard;
+                let _in_flight_guard = in_flight_guard;
+                epoch_store
+                    .within_alive_epoch(scheduler.schedule_transaction(
+                        cert,
+                        expected_effects_digest,
+                        &epoch_store,
+                        scheduling_source,
+                    ))
+                    .await
+            }
+            .instrument(span),
+            "schedule_transaction"
+        );
+    }
+
     async fn schedule_transaction(
         self,
         cert: VerifiedExecutableTransaction,
@@ -92,10 +242,25 @@ impl ExecutionScheduler {
         scheduling_source: SchedulingSource,
     ) {
         let enqueue_time = Instant::now();
+        let _scheduling_guard =
+            GaugeGuard::acquire(&self.metrics.execution_scheduler_num_scheduling_tasks);
         let tx_data = cert.transaction_data();
-        let input_object_kinds = tx_data
-            .input_objects()
-            .expect("input_objects() cannot fail");
+        let input_object_kinds = match tx_data.input_objects() {
+            Ok(kinds) => kinds,
+            Err(e) => {
+                // Certificates are validated before they are scheduled, so this indicates a bug.
+                debug_fatal!(
+                    "Failed to get input objects of transaction {:?}: {:?}",
+                    cert.digest(),
+                    e
+                );
+                self.metrics
+                    .transaction_manager_num_enqueued_certificates
+                    .with_label_values(&["invalid_input_objects"])
+                    .inc();
+                return;
+            }
+        };
         let input_object_keys: Vec<_> =
             match epoch_store.get_input_object_keys(&cert.key(), &input_object_kinds) {
                 Ok(keys) => keys,
@@ -121,6 +286,9 @@ impl ExecutionScheduler {
             .map(|entry| {
                 InputKey::VersionedObject {
                     // TODO: Add support for receiving ConsensusV2 objects. For now this assumes fastpath.
+                    // Execution only receives address-owned objects, and marks them received with a
+                    // fastpath key. A consensus key must not be used here until execution does the same,
+                    // otherwise the wait below would never be notified.
                     id: FullObjectID::new(entry.0, None),
                     version: entry.1,
                 }
@@ -148,6 +316,8 @@ impl ExecutionScheduler {
         // Most of the times, the transaction's input objects are already available.
         // We can check the availability of the input objects first, and only wait for the
         // missing input objects if necessary.
+        // Inputs missing from the cache, including gas objects, are not prefetched: the wait
+        // below first reads them from the store, and only waits if they are not there either.
         let missing_input_keys: Vec<_> = input_and_receiving_keys
             .into_iter()
             .zip(availability)
@@ -173,20 +343,64 @@ impl ExecutionScheduler {
             .transaction_manager_num_enqueued_certificates
             .with_label_values(&["pending"])
             .inc();
-        tokio::select! {
-            _ = self.object_cache_read
+        // Receiving objects are pinned to a version that may never be written if the object is
+        // received elsewhere first, so waits on them are tracked separately from regular inputs.
+        let missing_receiving_keys: Vec<_> = missing_input_keys
+            .iter()
+            .filter(|key| receiving_object_keys.contains(key))
+            .collect();
+        if !missing_receiving_keys.is_empty() {
+            self.metrics
+                .execution_scheduler_receiving_object_waits
+                .inc();
+            debug!(
+                ?digest,
+                ?missing_receiving_keys,
+                "Waiting for receiving objects"
+            );
+        }
+        let wait_for_input_objects = async {
+            let notify_read = self
+                .object_cache_read
                 .notify_read_input_objects(&missing_input_keys, &receiving_object_keys, &epoch)
-                => {
-                    self.metrics
-                        .transaction_manager_transaction_queue_age_s
-                        .observe(enqueue_time.elapsed().as_secs_f64());
-                    debug!(?digest, "Input objects available");
-                    // TODO: Eventually we could fold execution_driver into the scheduler.
-                    self.send_transaction_for_execution(&cert, expected_effects_digest, enqueue_time, scheduling_source);
-                }
+                .instrument(debug_span!("wait_for_input_objects"));
+            tokio::pin!(notify_read);
+            let threshold = self.input_objects_wait_warn_threshold;
+            if tokio::time::timeout(threshold, &mut notify_read)
+                .await
+                .is_err()
+            {
+                // Keep waiting after logging. The missing keys show what a stuck transaction
+                // is waiting for.
+                warn!(
+                    ?digest,
+                    ?missing_input_keys,
+                    waited_ms = threshold.as_millis() as u64,
+                    "Transaction is still waiting for input objects"
+                );
+                notify_read.await;
+            }
+        };
+        tokio::select! {
+            _ = wait_for_input_objects => {
+                self.metrics
+                    .transaction_manager_transaction_queue_age_s
+                    .observe(enqueue_time.elapsed().as_secs_f64());
+                debug!(?digest, "Input objects available");
+                // TODO: Eventually we could fold execution_driver into the scheduler.
+                self.send_transaction_for_execution(
+                    &cert,
+                    expected_effects_digest,
+                    enqueue_time,
+                    scheduling_source,
+                );
+            }
             _ = self.transaction_cache_read.notify_read_executed_effects_digests(&digests) => {
                 debug!(?digests, "Transaction already executed");
             }
+            _ = self.abort_notify.wait() => {
+                warn!(?digest, "Execution scheduler aborted, dropping transaction waiting for input objects");
+            }
         };
     }
 
@@ -197,6 +411,7 @@ impl ExecutionScheduler {
         enqueue_time: Instant,
         scheduling_source: SchedulingSource,
     ) {
+        let _span = debug_span!("send_for_execution").entered();
         let pending_cert = PendingCertificate {
             certificate: cert.clone(),
             expected_effects_digest,
@@ -211,8 +426,23 @@ impl ExecutionScheduler {
                     .clone(),
             )),
             scheduling_source,
+            priority: TransactionPriority::of(cert),
         };
-        let _ = self.tx_ready_certificates.send(pending_cert);
+        let digest = *cert.digest();
+        match self.tx_ready_certificates.send(pending_cert) {
+            Ok(()) => {
+                self.metrics
+                    .execution_scheduler_schedule_latency_s
+                    .observe(enqueue_time.elapsed().as_secs_f64());
+            }
+            Err(_) => {
+                // The execution driver has stopped, e.g. during shutdown.
+                warn!(
+                    ?digest,
+                    "Ready certificates receiver dropped, not sending transaction for execution"
+                );
+            }
+        }
     }
 }
 
@@ -226,7 +456,19 @@ impl ExecutionSchedulerAPI for ExecutionScheduler {
         epoch_store: &Arc<AuthorityPerEpochStore>,
         scheduling_source: SchedulingSource,
     ) {
+        if self.shutting_down.load(Ordering::Relaxed) {
+            warn!(
+                "Execution scheduler is shutting down, dropping {} enqueued certificates",
+                certs.len()
+            );
+            self.metrics
+                .transaction_manager_num_enqueued_certificates
+                .with_label_values(&["shutdown"])
+                .inc_by(certs.len() as u64);
+            return;
+        }
         // Filter out certificates from wrong epoch.
+        let mut wrong_epoch_certs_num = 0;
         let certs: Vec<_> = certs
             .into_iter()
             .filter_map(|cert| {
@@ -234,14 +476,20 @@ impl ExecutionSchedulerAPI for ExecutionScheduler {
                     Some(cert)
                 } else {
                     warn!(
-                        "Ignoring enqueued certificate from wrong epoch. Expected={} Certificate={:?}",
-                        epoch_store.epoch(),
-                        cert.0.epoch(),
+                        tx_digest = ?cert.0.digest(),
+                        expected_epoch = epoch_store.epoch(),
+                        actual_epoch = cert.0.epoch(),
+                        "Ignoring enqueued certificate from wrong epoch",
                     );
+                    wrong_epoch_certs_num += 1;
                     None
                 }
             })
             .collect();
+        self.metrics
+            .transaction_manager_num_enqueued_certificates
+            .with_label_values(&["wrong_epoch"])
+            .inc_by(wrong_epoch_certs_num);
         let digests: Vec<_> = certs.iter().map(|(cert, _)| *cert.digest()).collect();
         let executed = self
             .transaction_cache_read
@@ -259,15 +507,16 @@ impl ExecutionSchedulerAPI for ExecutionScheduler {
         );
 
         for (cert, expected_effects_digest) in pending_certs {
-            let scheduler = self.clone();
-            let epoch_store = epoch_store.clone();
-            spawn_monitored_task!(
-                epoch_store.within_alive_epoch(scheduler.schedule_transaction(
-                    cert,
-                    expected_effects_digest,
-                    &epoch_store,
-                    scheduling_source,
-                ))
+            // An in-flight task for the same transaction and source sends it for execution as
+            // well. Certificates with expected effects, e.g. from checkpoints, are always
+            // scheduled, so that the expected effects reach the executor.
+            let skip_if_in_flight = expected_effects_digest.is_none();
+            self.spawn_schedule_transaction(
+                cert,
+                expected_effects_digest,
+                epoch_store,
+                scheduling_source,
+                skip_if_in_flight,
             );
         }
 
@@ -282,7 +531,13 @@ impl ExecutionSchedulerAPI for ExecutionScheduler {
         overload_config: &AuthorityOverloadConfig,
         tx_data: &SenderSignedData,
     ) -> SuiResult {
-        let inflight_queue_len = self.num_pending_certificates();
+        // Transactions are counted from the time their scheduling task is spawned, instead of
+        // once they start waiting for input objects.
+        let inflight_queue_len = self.pending_count()
+            + self
+                .metrics
+                .transaction_manager_num_executing_certificates
+                .get() as usize;
         self.overload_tracker
             .check_execution_overload(overload_config, tx_data, inflight_queue_len)
     }
@@ -308,40 +563,44 @@ impl ExecutionSchedulerAPI for ExecutionScheduler {
 mod test {
     use std::{time::Duration, vec};
 
+    use sui_config::node::AuthorityOverloadConfig;
     use sui_test_transaction_builder::TestTransactionBuilder;
+    use sui_types::error::SuiError;
     use sui_types::executable_transaction::VerifiedExecutableTransaction;
     use sui_types::object::Owner;
     use sui_types::transaction::VerifiedTransaction;
     use sui_types::{
         base_types::{ObjectID, SequenceNumber},
         crypto::deterministic_random_account_key,
+        digests::TransactionEffectsDigest,
         object::Object,
         transaction::{CallArg, ObjectArg},
         SUI_FRAMEWORK_PACKAGE_ID,
     };
     use tokio::time::Instant;
-    use tokio::{
-        sync::mpsc::{error::TryRecvError, unbounded_channel, UnboundedReceiver},
-        time::sleep,
-    };
+    use tokio::{sync::mpsc::error::TryRecvError, time::sleep};
 
     use crate::authority::{authority_tests::init_state_with_objects, AuthorityState};
     use crate::execution_scheduler::{
-        ExecutionSchedulerAPI, ExecutionSchedulerWrapper, SchedulingSource,
+        ready_certificates_channel, ExecutionSchedulerAPI, ExecutionSchedulerWrapper,
+        PendingCertificateStats, ReadyCertificatesReceiver, SchedulingSource, TransactionPriority,
     };
 
     use super::{ExecutionScheduler, PendingCertificate};
 
-    #[allow(clippy::disallowed_methods)] // allow unbounded_channel()
     fn make_execution_scheduler(
         state: &AuthorityState,
-    ) -> (
-        ExecutionSchedulerWrapper,
-        UnboundedReceiver<PendingCertificate>,
-    ) {
+    ) -> (ExecutionSchedulerWrapper, ReadyCertificatesReceiver) {
+        make_execution_scheduler_with_config(state, &AuthorityOverloadConfig::default())
+    }
+
+    fn make_execution_scheduler_with_config(
+        state: &AuthorityState,
+        overload_config: &AuthorityOverloadConfig,
+    ) -> (ExecutionSchedulerWrapper, ReadyCertificatesReceiver) {
         // Create a new execution scheduler instead of reusing the authority's, to examine
         // execution_scheduler output from rx_ready_certificates.
-        let (tx_ready_certificates, rx_ready_certificates) = unbounded_channel();
+        let (tx_ready_certificates, rx_ready_certificates) = ready_certificates_channel();
         // Do not call ExecutionSchedulerWrapper::new() here, because we want to always
         // construct an ExecutionScheduler in the tests here, not TransactionManager.
         let execution_scheduler =
@@ -349,6 +608,7 @@ mod test {
                 state.get_object_cache_reader().clone(),
                 state.get_transaction_cache_reader().clone(),
                 tx_ready_certificates,
+                overload_config,
                 state.metrics.clone(),
             ));
 
@@ -449,7 +709,7 @@ mod test {
 
         assert_eq!(execution_scheduler.num_pending_certificates(), 1);
 
-        // Duplicated enqueue is allowed.
+        // Duplicated enqueue is skipped while the transaction is being scheduled.
         execution_scheduler.enqueue(
             vec![transaction.clone()],
             &state.epoch_store_for_testing(),
@@ -460,20 +720,22 @@ mod test {
             .try_recv()
             .is_err_and(|err| err == TryRecvError::Empty));
 
-        assert_eq!(execution_scheduler.num_pending_certificates(), 2);
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
 
         // Notify scheduler about availability of the gas object.
         state
             .get_cache_writer()
             .write_object_entry_for_test(gas_object_new);
-        // scheduler should output the transaction eventually.
-        // We will see both the original and the duplicated transaction.
+        // scheduler should output the transaction eventually, only once.
         let pending_certificate = rx_ready_certificates.recv().await.unwrap();
-        let pending_certificate2 = rx_ready_certificates.recv().await.unwrap();
         assert_eq!(
             pending_certificate.certificate.digest(),
-            pending_certificate2.certificate.digest()
+            transaction.digest()
         );
+        sleep(Duration::from_secs(1)).await;
+        assert!(rx_ready_certificates
+            .try_recv()
+            .is_err_and(|err| err == TryRecvError::Empty));
 
         // Tests that pending certificate stats are recorded properly. The ready time should be
         // 2 seconds apart from the enqueue time.
@@ -485,12 +747,475 @@ mod test {
 
         // Predent we have just executed the transaction.
         drop(pending_certificate);
-        drop(pending_certificate2);
 
         // scheduler should be empty at the end.
         execution_scheduler.check_empty_for_testing();
     }
 
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_waits_for_all_inputs() {
+        // Initialize an authority state.
+        let (owner, _keypair) = deterministic_random_account_key();
+        let state = init_state_with_objects(vec![]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+        let make_missing_object = || {
+            Object::with_id_owner_version_for_testing(
+                ObjectID::random(),
+                0.into(),
+                Owner::AddressOwner(owner),
+            )
+        };
+
+        // Enqueue a transaction whose gas object and owned input object are both missing.
+        let gas_object = make_missing_object();
+        let input_object = make_missing_object();
+        let transaction = make_transaction(
+            gas_object.clone(),
+            vec![CallArg::Object(ObjectArg::ImmOrOwnedObject(
+                input_object.compute_object_reference(),
+            ))],
+        );
+        execution_scheduler.enqueue(
+            vec![transaction.clone()],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+
+        // The transaction keeps waiting while only some of its inputs are available.
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(gas_object);
+        sleep(Duration::from_secs(1)).await;
+        assert!(rx_ready_certificates
+            .try_recv()
+            .is_err_and(|err| err == TryRecvError::Empty));
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+
+        // The transaction is sent for execution once the last input becomes available.
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(input_object);
+        let pending_certificate = rx_ready_certificates.recv().await.unwrap();
+        assert_eq!(
+            pending_certificate.certificate.digest(),
+            transaction.digest()
+        );
+
+        // Pretend we have just executed the transaction.
+        drop(pending_certificate);
+        execution_scheduler.check_empty_for_testing();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_metrics() {
+        // Initialize an authority state.
+        let (owner, _keypair) = deterministic_random_account_key();
+        let state = init_state_with_objects(vec![]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+        let metrics = state.metrics.clone();
+        let initial_sample_count = metrics
+            .execution_scheduler_schedule_latency_s
+            .get_sample_count();
+
+        // Enqueue a transaction whose gas object is not available yet.
+        let gas_object = Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            0.into(),
+            Owner::AddressOwner(owner),
+        );
+        execution_scheduler.enqueue(
+            vec![make_transaction(gas_object.clone(), vec![])],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(metrics.execution_scheduler_num_scheduling_tasks.get(), 1);
+
+        // Once the transaction is sent for execution, its schedule latency is recorded.
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(gas_object);
+        let pending_certificate = rx_ready_certificates.recv().await.unwrap();
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(
+            metrics
+                .execution_scheduler_schedule_latency_s
+                .get_sample_count(),
+            initial_sample_count + 1
+        );
+        assert_eq!(metrics.execution_scheduler_num_scheduling_tasks.get(), 0);
+
+        // Pretend we have just executed the transaction.
+        drop(pending_certificate);
+        execution_scheduler.check_empty_for_testing();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_overload() {
+        // Initialize an authority state.
+        let (owner, _keypair) = deterministic_random_account_key();
+        let state = init_state_with_objects(vec![]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+
+        // Enqueue transactions whose gas objects are not available yet.
+        let gas_objects: Vec<Object> = (0..3)
+            .map(|_| {
+                Object::with_id_owner_version_for_testing(
+                    ObjectID::random(),
+                    0.into(),
+                    Owner::AddressOwner(owner),
+                )
+            })
+            .collect();
+        let transactions: Vec<_> = gas_objects
+            .iter()
+            .map(|gas_object| make_transaction(gas_object.clone(), vec![]))
+            .collect();
+        execution_scheduler.enqueue(
+            transactions.clone(),
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(execution_scheduler.num_pending_certificates(), 3);
+
+        // New transactions are rejected once the queue length reaches the limit.
+        let overload_config = AuthorityOverloadConfig {
+            max_transaction_manager_queue_length: 3,
+            ..Default::default()
+        };
+        let result =
+            execution_scheduler.check_execution_overload(&overload_config, transactions[0].data());
+        assert!(matches!(
+            result,
+            Err(SuiError::TooManyTransactionsPendingExecution {
+                queue_len: 3,
+                threshold: 3,
+            })
+        ));
+        let overload_config = AuthorityOverloadConfig {
+            max_transaction_manager_queue_length: 4,
+            ..Default::default()
+        };
+        assert!(execution_scheduler
+            .check_execution_overload(&overload_config, transactions[0].data())
+            .is_ok());
+
+        // Make the gas objects available and pretend the transactions are executed.
+        for gas_object in gas_objects {
+            state
+                .get_cache_writer()
+                .write_object_entry_for_test(gas_object);
+        }
+        for _ in 0..3 {
+            drop(rx_ready_certificates.recv().await.unwrap());
+        }
+
+        // The queue is drained so transactions are no longer rejected.
+        let overload_config = AuthorityOverloadConfig {
+            max_transaction_manager_queue_length: 3,
+            ..Default::default()
+        };
+        assert!(execution_scheduler
+            .check_execution_overload(&overload_config, transactions[0].data())
+            .is_ok());
+        execution_scheduler.check_empty_for_testing();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_waits_past_input_objects_warn_threshold() {
+        let (owner, _keypair) = deterministic_random_account_key();
+        let state = init_state_with_objects(vec![]).await;
+        let overload_config = AuthorityOverloadConfig {
+            input_objects_wait_warn_threshold: Duration::from_secs(1),
+            ..Default::default()
+        };
+        let (execution_scheduler, mut rx_ready_certificates) =
+            make_execution_scheduler_with_config(&state, &overload_config);
+
+        let gas_object = Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            0.into(),
+            Owner::AddressOwner(owner),
+        );
+        let transaction = make_transaction(gas_object.clone(), vec![]);
+        execution_scheduler.enqueue(
+            vec![transaction.clone()],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+
+        // The transaction keeps waiting after the warning threshold is exceeded.
+        sleep(Duration::from_secs(5)).await;
+        assert!(rx_ready_certificates
+            .try_recv()
+            .is_err_and(|err| err == TryRecvError::Empty));
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(gas_object);
+        let pending_certificate = rx_ready_certificates.recv().await.unwrap();
+        assert_eq!(
+            pending_certificate.certificate.digest(),
+            transaction.digest()
+        );
+        drop(pending_certificate);
+        execution_scheduler.check_empty_for_testing();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_abort() {
+        // Initialize an authority state.
+        let (owner, _keypair) = deterministic_random_account_key();
+        let state = init_state_with_objects(vec![]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+        let make_missing_gas_object = || {
+            Object::with_id_owner_version_for_testing(
+                ObjectID::random(),
+                0.into(),
+                Owner::AddressOwner(owner),
+            )
+        };
+
+        // Enqueue a transaction whose gas object is not available.
+        let gas_object = make_missing_gas_object();
+        execution_scheduler.enqueue(
+            vec![make_transaction(gas_object.clone(), vec![])],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+
+        // Aborting drops the waiting transaction.
+        let ExecutionSchedulerWrapper::ExecutionScheduler(scheduler) = &execution_scheduler else {
+            unreachable!("make_execution_scheduler always creates an ExecutionScheduler");
+        };
+        scheduler.abort();
+        sleep(Duration::from_secs(1)).await;
+        execution_scheduler.check_empty_for_testing();
+
+        // Transactions enqueued after abort are dropped as well.
+        execution_scheduler.enqueue(
+            vec![make_transaction(make_missing_gas_object(), vec![])],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        execution_scheduler.check_empty_for_testing();
+
+        // The transaction is not sent for execution even if its input becomes available.
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(gas_object);
+        sleep(Duration::from_secs(1)).await;
+        assert!(rx_ready_certificates
+            .try_recv()
+            .is_err_and(|err| err == TryRecvError::Empty));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_shutdown() {
+        // Initialize an authority state.
+        let (owner, _keypair) = deterministic_random_account_key();
+        let state = init_state_with_objects(vec![]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+        let ExecutionSchedulerWrapper::ExecutionScheduler(scheduler) = &execution_scheduler else {
+            unreachable!("make_execution_scheduler always creates an ExecutionScheduler");
+        };
+        let make_missing_gas_object = || {
+            Object::with_id_owner_version_for_testing(
+                ObjectID::random(),
+                0.into(),
+                Owner::AddressOwner(owner),
+            )
+        };
+        let shutdown_certs = || {
+            state
+                .metrics
+                .transaction_manager_num_enqueued_certificates
+                .with_label_values(&["shutdown"])
+                .get()
+        };
+
+        // Enqueue a transaction whose gas object is not available.
+        let gas_object = make_missing_gas_object();
+        let transaction = make_transaction(gas_object.clone(), vec![]);
+        execution_scheduler.enqueue(
+            vec![transaction.clone()],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+
+        // Draining waits for the scheduled transaction.
+        let drain = tokio::spawn(scheduler.shutdown());
+        sleep(Duration::from_secs(1)).await;
+        assert!(!drain.is_finished());
+
+        // Transactions enqueued after shutdown are dropped.
+        let initial_shutdown_certs = shutdown_certs();
+        execution_scheduler.enqueue(
+            vec![make_transaction(make_missing_gas_object(), vec![])],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(shutdown_certs(), initial_shutdown_certs + 1);
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+        assert!(!drain.is_finished());
+
+        // The scheduled transaction is still sent for execution once its input is available,
+        // after which draining completes.
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(gas_object);
+        let pending_certificate = rx_ready_certificates.recv().await.unwrap();
+        assert_eq!(
+            pending_certificate.certificate.digest(),
+            transaction.digest()
+        );
+        drain.await.unwrap();
+        execution_scheduler.check_empty_for_testing();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_wrong_epoch() {
+        // Initialize an authority state.
+        let (owner, _keypair) = deterministic_random_account_key();
+        let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), owner);
+        let state = init_state_with_objects(vec![gas_object.clone()]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+        let epoch_store = state.epoch_store_for_testing();
+        let wrong_epoch_certs = || {
+            state
+                .metrics
+                .transaction_manager_num_enqueued_certificates
+                .with_label_values(&["wrong_epoch"])
+                .get()
+        };
+        let initial_wrong_epoch_certs = wrong_epoch_certs();
+
+        // Enqueue a transaction certified in a different epoch.
+        let transaction = make_transaction(gas_object, vec![]);
+        let wrong_epoch_transaction = VerifiedExecutableTransaction::new_system(
+            transaction.clone().into_unsigned(),
+            epoch_store.epoch() + 1,
+        );
+        execution_scheduler.enqueue(
+            vec![wrong_epoch_transaction],
+            &epoch_store,
+            SchedulingSource::NonFastPath,
+        );
+
+        // The transaction is dropped and recorded in metrics.
+        sleep(Duration::from_secs(1)).await;
+        assert!(rx_ready_certificates
+            .try_recv()
+            .is_err_and(|err| err == TryRecvError::Empty));
+        assert_eq!(wrong_epoch_certs(), initial_wrong_epoch_certs + 1);
+        execution_scheduler.check_empty_for_testing();
+
+        // The same transaction from the current epoch is scheduled.
+        execution_scheduler.enqueue(
+            vec![transaction.clone()],
+            &epoch_store,
+            SchedulingSource::NonFastPath,
+        );
+        let pending_certificate = rx_ready_certificates.recv().await.unwrap();
+        assert_eq!(
+            pending_certificate.certificate.digest(),
+            transaction.digest()
+        );
+        assert_eq!(wrong_epoch_certs(), initial_wrong_epoch_certs + 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_epoch_transition() {
+        // Initialize an authority state.
+        let (owner, _keypair) = deterministic_random_account_key();
+        let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), owner);
+        let state = init_state_with_objects(vec![gas_object.clone()]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+        let wrong_epoch_certs = || {
+            state
+                .metrics
+                .transaction_manager_num_enqueued_certificates
+                .with_label_values(&["wrong_epoch"])
+                .get()
+        };
+        let initial_wrong_epoch_certs = wrong_epoch_certs();
+
+        // Certificates of the old epoch: one waiting for a missing gas object, and one ready.
+        let old_epoch_store = state.epoch_store_for_testing().clone();
+        let missing_gas_object = Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            0.into(),
+            Owner::AddressOwner(owner),
+        );
+        let pending_transaction = make_transaction(missing_gas_object.clone(), vec![]);
+        let transaction = make_transaction(gas_object, vec![]);
+        assert_eq!(transaction.epoch(), old_epoch_store.epoch());
+        execution_scheduler.enqueue(
+            vec![pending_transaction.clone()],
+            &old_epoch_store,
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+
+        // Advancing the epoch drops the transaction still waiting for inputs.
+        state.reconfigure_for_testing().await;
+        let new_epoch_store = state.epoch_store_for_testing().clone();
+        assert_eq!(new_epoch_store.epoch(), old_epoch_store.epoch() + 1);
+        sleep(Duration::from_secs(1)).await;
+        execution_scheduler.check_empty_for_testing();
+
+        // Certificates of the old epoch are dropped without being sent for execution, even
+        // once their inputs are available.
+        execution_scheduler.enqueue(
+            vec![transaction.clone(), pending_transaction],
+            &new_epoch_store,
+            SchedulingSource::NonFastPath,
+        );
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(missing_gas_object);
+        sleep(Duration::from_secs(1)).await;
+        assert!(rx_ready_certificates
+            .try_recv()
+            .is_err_and(|err| err == TryRecvError::Empty));
+        assert_eq!(wrong_epoch_certs(), initial_wrong_epoch_certs + 2);
+        execution_scheduler.check_empty_for_testing();
+
+        // Certificates of the new epoch are scheduled.
+        let new_epoch_transaction = VerifiedExecutableTransaction::new_system(
+            transaction.clone().into_unsigned(),
+            new_epoch_store.epoch(),
+        );
+        execution_scheduler.enqueue(
+            vec![new_epoch_transaction],
+            &new_epoch_store,
+            SchedulingSource::NonFastPath,
+        );
+        let pending_certificate = rx_ready_certificates.recv().await.unwrap();
+        assert_eq!(
+            pending_certificate.certificate.digest(),
+            transaction.digest()
+        );
+        assert_eq!(
+            pending_certificate.certificate.epoch(),
+            new_epoch_store.epoch()
+        );
+        assert_eq!(wrong_epoch_certs(), initial_wrong_epoch_certs + 2);
+        drop(pending_certificate);
+        execution_scheduler.check_empty_for_testing();
+    }
+
     // Tests when objects become available, correct set of transactions can be sent to execute.
     // Specifically, we have following setup,
     //         shared_object     shared_object_2
@@ -833,6 +1558,14 @@ mod test {
             vec![CallArg::Object(receiving_object_arg1)],
         );
 
+        let receiving_object_waits = || {
+            state
+                .metrics
+                .execution_scheduler_receiving_object_waits
+                .get()
+        };
+        let initial_receiving_object_waits = receiving_object_waits();
+
         // scheduler should output no transaction yet since waiting on receiving object.
         execution_scheduler.enqueue(
             vec![receive_object_transaction0.clone()],
@@ -842,6 +1575,7 @@ mod test {
         sleep(Duration::from_secs(1)).await;
         assert!(rx_ready_certificates.try_recv().is_err());
         assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+        assert_eq!(receiving_object_waits(), initial_receiving_object_waits + 1);
 
         // scheduler should output no transaction yet since waiting on receiving object.
         execution_scheduler.enqueue(
@@ -853,7 +1587,7 @@ mod test {
         assert!(rx_ready_certificates.try_recv().is_err());
         assert_eq!(execution_scheduler.num_pending_certificates(), 2);
 
-        // Duplicate enqueue of receiving object is allowed.
+        // Duplicate enqueue of receiving object is skipped.
         execution_scheduler.enqueue(
             vec![receive_object_transaction0.clone()],
             &state.epoch_store_for_testing(),
@@ -861,7 +1595,8 @@ mod test {
         );
         sleep(Duration::from_secs(1)).await;
         assert!(rx_ready_certificates.try_recv().is_err());
-        assert_eq!(execution_scheduler.num_pending_certificates(), 3);
+        assert_eq!(execution_scheduler.num_pending_certificates(), 2);
+        assert_eq!(receiving_object_waits(), initial_receiving_object_waits + 2);
 
         // Notify scheduler that the receiving object 0 is available.
         state
@@ -1187,4 +1922,206 @@ mod test {
 
         execution_scheduler.check_empty_for_testing();
     }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_receiver_dropped() {
+        let (owner, _keypair) = deterministic_random_account_key();
+        let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), owner);
+        let state = init_state_with_objects(vec![gas_object.clone()]).await;
+
+        let (execution_scheduler, rx_ready_certificates) = make_execution_scheduler(&state);
+        // Simulate the execution driver stopping during shutdown.
+        drop(rx_ready_certificates);
+
+        // Enqueueing a transaction whose inputs are all available must not panic.
+        let transaction = make_transaction(gas_object.clone(), vec![]);
+        execution_scheduler.enqueue(
+            vec![transaction],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+
+        execution_scheduler.check_empty_for_testing();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_pending_count() {
+        let (owner, _keypair) = deterministic_random_account_key();
+        let state = init_state_with_objects(vec![]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+        let ExecutionSchedulerWrapper::ExecutionScheduler(scheduler) = &execution_scheduler else {
+            panic!("Expected ExecutionScheduler");
+        };
+        assert_eq!(scheduler.pending_count(), 0);
+
+        // Transactions are counted as soon as they are enqueued, and while they wait for inputs.
+        let gas_object = Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            0.into(),
+            Owner::AddressOwner(owner),
+        );
+        let transaction = make_transaction(gas_object.clone(), vec![]);
+        execution_scheduler.enqueue(
+            vec![transaction],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        assert_eq!(scheduler.pending_count(), 1);
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(scheduler.pending_count(), 1);
+
+        // Sent for execution, the transaction is no longer pending.
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(gas_object);
+        let pending_certificate = rx_ready_certificates.recv().await.unwrap();
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(scheduler.pending_count(), 0);
+        drop(pending_certificate);
+
+        // Tasks dropped by the end of the epoch are no longer counted either.
+        let gas_object = Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            0.into(),
+            Owner::AddressOwner(owner),
+        );
+        execution_scheduler.enqueue(
+            vec![make_transaction(gas_object, vec![])],
+            &state.epoch_store_for_testing(),
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(scheduler.pending_count(), 1);
+        state.epoch_store_for_testing().epoch_terminated().await;
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(scheduler.pending_count(), 0);
+        execution_scheduler.check_empty_for_testing();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn execution_scheduler_skips_duplicate_enqueue() {
+        let (owner, _keypair) = deterministic_random_account_key();
+        let state = init_state_with_objects(vec![]).await;
+        let (execution_scheduler, mut rx_ready_certificates) = make_execution_scheduler(&state);
+        let duplicate_certs = || {
+            state
+                .metrics
+                .transaction_manager_num_enqueued_certificates
+                .with_label_values(&["duplicate"])
+                .get()
+        };
+        let initial_duplicate_certs = duplicate_certs();
+
+        let gas_object = Object::with_id_owner_version_for_testing(
+            ObjectID::random(),
+            0.into(),
+            Owner::AddressOwner(owner),
+        );
+        let transaction = make_transaction(gas_object.clone(), vec![]);
+        let epoch_store = state.epoch_store_for_testing();
+
+        // Duplicates within a batch and across batches are skipped.
+        execution_scheduler.enqueue(
+            vec![transaction.clone(), transaction.clone()],
+            &epoch_store,
+            SchedulingSource::NonFastPath,
+        );
+        execution_scheduler.enqueue(
+            vec![transaction.clone()],
+            &epoch_store,
+            SchedulingSource::NonFastPath,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(execution_scheduler.num_pending_certificates(), 1);
+        assert_eq!(duplicate_certs(), initial_duplicate_certs + 2);
+
+        // The same transaction from another scheduling source, or with expected effects,
+        // is still scheduled.
+        execution_scheduler.enqueue(
+            vec![transaction.clone()],
+            &epoch_store,
+            SchedulingSource::MysticetiFastPath,
+        );
+        execution_scheduler.enqueue_with_expected_effects_digest(
+            vec![(transaction.clone(), TransactionEffectsDigest::random())],
+            &epoch_store,
+        );
+        sleep(Duration::from_secs(1)).await;
+        assert_eq!(execution_scheduler.num_pending_certificates(), 3);
+        assert_eq!(duplicate_certs(), initial_duplicate_certs + 2);
+
+        state
+            .get_cache_writer()
+            .write_object_entry_for_test(gas_object);
+        for _ in 0..3 {
+            drop(rx_ready_certificates.recv().await.unwrap());
+        }
+        sleep(Duration::from_secs(1)).await;
+        execution_scheduler.check_empty_for_testing();
+
+        // Once the scheduling task exits, the transaction can be enqueued again.
+        execution_scheduler.enqueue(
+            vec![transaction.clone()],
+            &epoch_store,
+            SchedulingSource::NonFastPath,
+        );
+        drop(rx_ready_certificates.recv().await.unwrap());
+        assert_eq!(duplicate_certs(), initial_duplicate_certs + 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn ready_certificates_channel_prioritizes_system_transactions() {
+        let (owner, _keypair) = deterministic_random_account_key();
+        let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), owner);
+
+        let make_pending = |certificate: VerifiedExecutableTransaction| PendingCertificate {
+            priority: TransactionPriority::of(&certificate),
+            certificate,
+            expected_effects_digest: None,
+            waiting_input_objects: Default::default(),
+            stats: PendingCertificateStats {
+                enqueue_time: Instant::now(),
+                ready_time: Some(Instant::now()),
+            },
+            executing_guard: None,
+            scheduling_source: SchedulingSource::NonFastPath,
+        };
+
+        let user_transaction = make_transaction(gas_object.clone(), vec![]);
+        let system_transaction = VerifiedExecutableTransaction::new_system(
+            VerifiedTransaction::new_consensus_commit_prologue(0, 0, 42),
+            0,
+        );
+        assert_eq!(
+            TransactionPriority::of(&user_transaction),
+            TransactionPriority::Normal
+        );
+        assert_eq!(
+            TransactionPriority::of(&system_transaction),
+            TransactionPriority::High
+        );
+
+        let (tx_ready_certificates, mut rx_ready_certificates) = ready_certificates_channel();
+        tx_ready_certificates
+            .send(make_pending(user_transaction.clone()))
+            .unwrap();
+        tx_ready_certificates
+            .send(make_pending(system_transaction.clone()))
+            .unwrap();
+
+        // The system transaction is received first even though it was sent last.
+        let pending = rx_ready_certificates.recv().await.unwrap();
+        assert_eq!(pending.certificate.digest(), system_transaction.digest());
+        let pending = rx_ready_certificates.try_recv().unwrap();
+        assert_eq!(pending.certificate.digest(), user_transaction.digest());
+        assert!(matches!(
+            rx_ready_certificates.try_recv(),
+            Err(TryRecvError::Empty)
+        ));
+
+        // Once all senders are dropped, recv() returns None.
+        drop(tx_ready_certificates);
+        assert!(rx_ready_certificates.recv().await.is_none());
+    }
 }
@@ -19,7 +19,10 @@ use sui_types::{
     storage::InputKey,
     transaction::{SenderSignedData, VerifiedCertificate},
 };
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::mpsc::{
+    error::{SendError, TryRecvError},
+    unbounded_channel, UnboundedReceiver, UnboundedSender,
+};
 use tokio::time::Instant;
 use transaction_manager::TransactionManager;
 
@@ -36,12 +39,30 @@ pub struct PendingCertificateStats {
     pub ready_time: Option<Instant>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum SchedulingSource {
     MysticetiFastPath,
     NonFastPath,
 }
 
+/// Priority of a certificate ready for execution.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TransactionPriority {
+    // System transactions, e.g. consensus commit prologues and randomness updates.
+    High,
+    Normal,
+}
+
+impl TransactionPriority {
+    pub fn of(cert: &VerifiedExecutableTransaction) -> Self {
+        if cert.is_system_tx() {
+            Self::High
+        } else {
+            Self::Normal
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct PendingCertificate {
     // Certified transaction to be executed.
@@ -56,6 +77,67 @@ pub struct PendingCertificate {
     pub stats: PendingCertificateStats,
     pub executing_guard: Option<ExecutingGuard>,
     pub scheduling_source: SchedulingSource,
+    pub priority: TransactionPriority,
+}
+
+/// Creates the channel sending certificates ready for execution to the execution driver.
+/// High priority certificates are received before normal priority ones, regardless of the
+/// order in which they were sent.
+#[allow(clippy::disallowed_methods)] // allow unbounded_channel()
+pub fn ready_certificates_channel() -> (ReadyCertificatesSender, ReadyCertificatesReceiver) {
+    let (high_tx, high_rx) = unbounded_channel();
+    let (normal_tx, normal_rx) = unbounded_channel();
+    (
+        ReadyCertificatesSender {
+            high: high_tx,
+            normal: normal_tx,
+        },
+        ReadyCertificatesReceiver {
+            high: high_rx,
+            normal: normal_rx,
+        },
+    )
+}
+
+#[derive(Clone)]
+pub struct ReadyCertificatesSender {
+    high: UnboundedSender<PendingCertificate>,
+    normal: UnboundedSender<PendingCertificate>,
+}
+
+impl ReadyCertificatesSender {
+    pub fn send(
+        &self,
+        pending_cert: PendingCertificate,
+    ) -> Result<(), SendError<PendingCertificate>> {
+        match pending_cert.priority {
+            TransactionPriority::High => self.high.send(pending_cert),
+            TransactionPriority::Normal => self.normal.send(pending_cert),
+        }
+    }
+}
+
+pub struct ReadyCertificatesReceiver {
+    high: UnboundedReceiver<PendingCertificate>,
+    normal: UnboundedReceiver<PendingCertificate>,
+}
+
+impl ReadyCertificatesReceiver {
+    /// Receives the next certificate, preferring high priority ones.
+    /// Returns None once all senders are dropped and no certificate is left.
+    /// Cancel safe, so it can be used in `tokio::select!`.
+    pub async fn recv(&mut self) -> Option<PendingCertificate> {
+        tokio::select! {
+            biased;
+            Some(pending_cert) = self.high.recv() => Some(pending_cert),
+            Some(pending_cert) = self.normal.recv() => Some(pending_cert),
+            else => None,
+        }
+    }
+
+    pub fn try_recv(&mut self) -> Result<PendingCertificate, TryRecvError> {
+        self.high.try_recv().or_else(|_| self.normal.try_recv())
+    }
 }
 
 #[derive(Debug)]
@@ -140,8 +222,9 @@ impl ExecutionSchedulerWrapper {
     pub fn new(
         object_cache_read: Arc<dyn ObjectCacheRead>,
         transaction_cache_read: Arc<dyn TransactionCacheRead>,
-        tx_ready_certificates: UnboundedSender<PendingCertificate>,
+        tx_ready_certificates: ReadyCertificatesSender,
         epoch_store: &Arc<AuthorityPerEpochStore>,
+        overload_config: &AuthorityOverloadConfig,
         metrics: Arc<AuthorityMetrics>,
     ) -> Self {
         // If Mysticeti fastpath is enabled, we must use ExecutionScheduler.
@@ -168,6 +251,7 @@ impl ExecutionSchedulerWrapper {
                 object_cache_read,
                 transaction_cache_read,
                 tx_ready_certificates,
+                overload_config,
                 metrics,
             ))
         } else {
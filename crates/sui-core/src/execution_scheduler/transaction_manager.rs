@@ -23,7 +23,6 @@ use sui_types::{
     transaction::TransactionDataAPI,
 };
 use sui_types::{executable_transaction::VerifiedExecutableTransaction, fp_bail};
-use tokio::sync::mpsc::UnboundedSender;
 use tokio::time::Instant;
 use tracing::{error, info, instrument, trace, warn};
 
@@ -35,7 +34,10 @@ use sui_config::node::AuthorityOverloadConfig;
 use sui_types::transaction::SenderSignedData;
 use tap::TapOptional;
 
-use super::{ExecutionSchedulerAPI, PendingCertificate, PendingCertificateStats, SchedulingSource};
+use super::{
+    ExecutionSchedulerAPI, PendingCertificate, PendingCertificateStats, ReadyCertificatesSender,
+    SchedulingSource, TransactionPriority,
+};
 
 /// Minimum capacity of HashMaps used in TransactionManager.
 const MIN_HASHMAP_CAPACITY: usize = 1000;
@@ -50,7 +52,7 @@ const MIN_HASHMAP_CAPACITY: usize = 1000;
 pub(crate) struct TransactionManager {
     object_cache_read: Arc<dyn ObjectCacheRead>,
     transaction_cache_read: Arc<dyn TransactionCacheRead>,
-    tx_ready_certificates: UnboundedSender<PendingCertificate>,
+    tx_ready_certificates: ReadyCertificatesSender,
     metrics: Arc<AuthorityMetrics>,
     // inner is a doubly nested lock so that we can enforce that an outer lock (for read) is held
     // before the inner lock (for read or write) can be acquired. During reconfiguration, we acquire
@@ -316,7 +318,7 @@ impl TransactionManager {
         object_cache_read: Arc<dyn ObjectCacheRead>,
         transaction_cache_read: Arc<dyn TransactionCacheRead>,
         epoch_store: &Arc<AuthorityPerEpochStore>,
-        tx_ready_certificates: UnboundedSender<PendingCertificate>,
+        tx_ready_certificates: ReadyCertificatesSender,
         metrics: Arc<AuthorityMetrics>,
     ) -> TransactionManager {
         tracing::info!("Creating new TransactionManager");
@@ -539,6 +541,7 @@ impl ExecutionSchedulerAPI for TransactionManager {
         let pending_cert_enqueue_time = Instant::now();
 
         for (cert, expected_effects_digest, input_object_keys) in certs {
+            let priority = TransactionPriority::of(&cert);
             pending.push(PendingCertificate {
                 certificate: cert,
                 expected_effects_digest,
@@ -549,6 +552,7 @@ impl ExecutionSchedulerAPI for TransactionManager {
                 },
                 executing_guard: None,
                 scheduling_source,
+                priority,
             });
         }
 
@@ -6647,3 +6647,73 @@ async fn test_single_authority_reconfigure() {
     state.reconfigure_for_testing().await;
     assert_eq!(state.epoch_store_for_testing().epoch(), 1);
 }
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_shutdown_execution_scheduler() {
+    let mut protocol_config = ProtocolConfig::get_for_max_version_UNSAFE();
+    protocol_config.set_mysticeti_fastpath_for_testing(true);
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config)
+        .build()
+        .await;
+    let ExecutionSchedulerWrapper::ExecutionScheduler(scheduler) =
+        state.execution_scheduler().as_ref()
+    else {
+        panic!("mysticeti fastpath always uses the ExecutionScheduler");
+    };
+    let epoch_store = state.epoch_store_for_testing();
+    let rgp = state.reference_gas_price_for_testing().unwrap();
+    // Transactions whose gas object does not exist wait for it until aborted.
+    let make_waiting_transaction = || {
+        let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+        let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+        let data = TransactionData::new_transfer_sui(
+            dbg_addr(2),
+            sender,
+            None,
+            gas_object.compute_object_reference(),
+            rgp * TEST_ONLY_GAS_UNIT_FOR_TRANSFER,
+            rgp,
+        );
+        VerifiedExecutableTransaction::new_system(
+            VerifiedTransaction::new_unchecked(to_sender_signed_transaction(data, &sender_key)),
+            epoch_store.epoch(),
+        )
+    };
+    let shutdown_certs = || {
+        state
+            .metrics
+            .transaction_manager_num_enqueued_certificates
+            .with_label_values(&["shutdown"])
+            .get()
+    };
+
+    state.enqueue_transactions_for_execution(vec![make_waiting_transaction()], &epoch_store);
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(scheduler.pending_count(), 1);
+
+    // Shutdown gives up on the waiting transaction after the timeout.
+    let start = tokio::time::Instant::now();
+    state
+        .shutdown_execution_scheduler(Duration::from_secs(5))
+        .await;
+    assert!(start.elapsed() >= Duration::from_secs(5));
+    assert_eq!(scheduler.pending_count(), 1);
+
+    // Transactions enqueued after shutdown are dropped.
+    let initial_shutdown_certs = shutdown_certs();
+    state.enqueue_transactions_for_execution(vec![make_waiting_transaction()], &epoch_store);
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(shutdown_certs(), initial_shutdown_certs + 1);
+    assert_eq!(scheduler.pending_count(), 1);
+
+    // Aborting drops the waiting transaction, after which shutdown returns immediately.
+    state.abort_execution_scheduler();
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(scheduler.pending_count(), 0);
+    let start = tokio::time::Instant::now();
+    state
+        .shutdown_execution_scheduler(Duration::from_secs(5))
+        .await;
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
@@ -9,6 +9,8 @@ use fastcrypto::traits::KeyPair;
 use sui_test_transaction_builder::TestTransactionBuilder;
 use sui_types::base_types::{ObjectRef, SuiAddress, TransactionDigest};
 use sui_types::crypto::{get_account_key_pair, AccountKeyPair};
+use sui_types::effects::TransactionEffectsAPI;
+use sui_types::error::SuiError;
 use sui_types::executable_transaction::VerifiedExecutableTransaction;
 use sui_types::message_envelope::Message;
 use sui_types::messages_grpc::RawWaitForEffectsRequest;
@@ -17,7 +19,7 @@ use sui_types::transaction::VerifiedTransaction;
 use sui_types::utils::to_sender_signed_transaction;
 
 use crate::authority::consensus_tx_status_cache::{
-    ConsensusTxStatus, CONSENSUS_STATUS_RETENTION_ROUNDS,
+    ConsensusRejectionReason, ConsensusTxStatus, CONSENSUS_STATUS_RETENTION_ROUNDS,
 };
 use crate::authority::test_authority_builder::TestAuthorityBuilder;
 use crate::authority::AuthorityState;
@@ -25,7 +27,8 @@ use crate::authority_client::{AuthorityAPI, NetworkAuthorityClient};
 use crate::authority_server::AuthorityServer;
 use crate::execution_scheduler::SchedulingSource;
 use crate::wait_for_effects_request::{
-    ConsensusTxPosition, RejectReason, WaitForEffectsRequest, WaitForEffectsResponse,
+    ConsensusRejectionCode, ConsensusTxPosition, RejectReason, WaitForEffectsRequest,
+    WaitForEffectsResponse,
 };
 
 use super::AuthorityServerHandle;
@@ -95,20 +98,16 @@ async fn test_wait_for_effects_position_mismatch() {
 
     let transaction = test_context.build_test_transaction();
     let tx_digest = *transaction.digest();
-    let tx_position1 = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN,
-    };
-    let tx_position2 = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN + 1,
-    };
+    let tx_position1 = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN);
+    let tx_position2 = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN + 1);
 
     let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
         epoch: 0,
         transaction_digest: tx_digest,
-        transaction_position: tx_position1,
+        transaction_position: Some(tx_position1),
         include_details: true,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
     })
     .unwrap();
 
@@ -140,16 +139,15 @@ async fn test_wait_for_effects_post_commit_rejected() {
 
     let transaction = test_context.build_test_transaction();
     let tx_digest = *transaction.digest();
-    let tx_position = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN,
-    };
+    let tx_position = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN);
 
     let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
         epoch: 0,
         transaction_digest: tx_digest,
-        transaction_position: tx_position,
+        transaction_position: Some(tx_position),
         include_details: true,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
     })
     .unwrap();
 
@@ -159,7 +157,10 @@ async fn test_wait_for_effects_post_commit_rejected() {
         let epoch_store = state_clone.epoch_store_for_testing();
         epoch_store.set_consensus_tx_status(tx_position, ConsensusTxStatus::FastpathCertified);
         tokio::time::sleep(Duration::from_millis(100)).await;
-        epoch_store.set_consensus_tx_status(tx_position, ConsensusTxStatus::Rejected);
+        epoch_store.set_consensus_tx_status(
+            tx_position,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::PostCommit),
+        );
     });
 
     let response = test_context
@@ -171,14 +172,129 @@ async fn test_wait_for_effects_post_commit_rejected() {
         .unwrap();
 
     match response {
-        WaitForEffectsResponse::Rejected { reason } => {
+        WaitForEffectsResponse::Rejected {
+            reason,
+            consensus_code,
+        } => {
             // TODO(fastpath): Test reject reason.
             assert_eq!(reason, RejectReason::None);
+            assert_eq!(consensus_code, Some(ConsensusRejectionCode::PostCommit));
         }
         _ => panic!("Expected Rejected response"),
     }
 }
 
+#[tokio::test]
+async fn test_wait_for_effects_without_position() {
+    // This test exercises the path where the request has no consensus position,
+    // so the validator only waits for the transaction to be executed.
+    let test_context = TestContext::new().await;
+
+    let transaction = test_context.build_test_transaction();
+    let tx_digest = *transaction.digest();
+
+    let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
+        epoch: 0,
+        transaction_digest: tx_digest,
+        transaction_position: None,
+        include_details: true,
+        include_auxiliary_data: true,
+        include_gas_summary: false,
+    })
+    .unwrap();
+
+    let state_clone = test_context.state.clone();
+    let handle = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let epoch_store = state_clone.epoch_store_for_testing();
+        state_clone
+            .try_execute_immediately(
+                &transaction,
+                None,
+                &epoch_store,
+                SchedulingSource::NonFastPath,
+            )
+            .await
+            .unwrap()
+            .0
+    });
+
+    let response: WaitForEffectsResponse = test_context
+        .client
+        .wait_for_effects(request, None)
+        .await
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let effects = handle.await.unwrap();
+
+    match response {
+        WaitForEffectsResponse::Executed {
+            effects_digest,
+            details,
+            ..
+        } => {
+            assert_eq!(effects_digest, effects.digest());
+            let details = details.expect("Details should be included");
+            assert_eq!(details.effects.digest(), effects.digest());
+            // The validator has not signed the effects, and does not sign them to respond.
+            assert!(details.signature.is_none());
+            // The gas object is returned as auxiliary data.
+            assert_eq!(
+                details
+                    .auxiliary_objects
+                    .iter()
+                    .map(Object::compute_object_reference)
+                    .collect::<Vec<_>>(),
+                vec![effects.gas_object().0]
+            );
+        }
+        _ => panic!("Expected Executed response"),
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_effects_returns_persisted_signature() {
+    // This test exercises the path where the validator has already signed the effects,
+    // in which case the persisted signature is returned with the details.
+    let test_context = TestContext::new().await;
+
+    let transaction = test_context.build_test_transaction();
+    let epoch_store = test_context.state.epoch_store_for_testing();
+    let (effects, _) = test_context
+        .state
+        .try_execute_immediately(
+            &transaction,
+            None,
+            &epoch_store,
+            SchedulingSource::NonFastPath,
+        )
+        .await
+        .unwrap();
+    test_context
+        .state
+        .sign_effects(effects.clone(), &epoch_store)
+        .unwrap();
+
+    let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
+        epoch: 0,
+        transaction_digest: *transaction.digest(),
+        transaction_position: None,
+        include_details: true,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
+    })
+    .unwrap();
+    let response: WaitForEffectsResponse = test_context
+        .client
+        .wait_for_effects(request, None)
+        .await
+        .unwrap()
+        .try_into()
+        .unwrap();
+    response.verify(epoch_store.committee()).unwrap();
+}
+
 #[tokio::test]
 async fn test_wait_for_effects_epoch_mismatch() {
     // This test exercises the path where the epoch of the request does not match the epoch
@@ -186,16 +302,15 @@ async fn test_wait_for_effects_epoch_mismatch() {
     let test_context = TestContext::new().await;
 
     let tx_digest = TransactionDigest::random();
-    let tx_position = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN,
-    };
+    let tx_position = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN);
 
     let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
         epoch: 1,
         transaction_digest: tx_digest,
-        transaction_position: tx_position,
+        transaction_position: Some(tx_position),
         include_details: true,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
     })
     .unwrap();
 
@@ -211,16 +326,15 @@ async fn test_wait_for_effects_timeout() {
     let test_context = TestContext::new().await;
 
     let tx_digest = TransactionDigest::random();
-    let tx_position = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN,
-    };
+    let tx_position = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN);
 
     let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
         epoch: 0,
         transaction_digest: tx_digest,
-        transaction_position: tx_position,
+        transaction_position: Some(tx_position),
         include_details: true,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
     })
     .unwrap();
 
@@ -236,16 +350,15 @@ async fn test_wait_for_effects_quorum_rejected() {
 
     let transaction = test_context.build_test_transaction();
     let tx_digest = *transaction.digest();
-    let tx_position = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN,
-    };
+    let tx_position = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN);
 
     let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
         epoch: 0,
         transaction_digest: tx_digest,
-        transaction_position: tx_position,
+        transaction_position: Some(tx_position),
         include_details: true,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
     })
     .unwrap();
 
@@ -253,7 +366,10 @@ async fn test_wait_for_effects_quorum_rejected() {
     tokio::spawn(async move {
         tokio::time::sleep(Duration::from_millis(100)).await;
         let epoch_store = state_clone.epoch_store_for_testing();
-        epoch_store.set_consensus_tx_status(tx_position, ConsensusTxStatus::Rejected);
+        epoch_store.set_consensus_tx_status(
+            tx_position,
+            ConsensusTxStatus::Rejected(ConsensusRejectionReason::FastPath),
+        );
     });
 
     let response = test_context
@@ -265,8 +381,12 @@ async fn test_wait_for_effects_quorum_rejected() {
         .unwrap();
 
     match response {
-        WaitForEffectsResponse::Rejected { reason } => {
+        WaitForEffectsResponse::Rejected {
+            reason,
+            consensus_code,
+        } => {
             assert_eq!(reason, RejectReason::None);
+            assert_eq!(consensus_code, Some(ConsensusRejectionCode::FastPath));
         }
         _ => panic!("Expected Rejected response"),
     }
@@ -280,17 +400,16 @@ async fn test_wait_for_effects_fastpath_certified() {
 
     let transaction = test_context.build_test_transaction();
     let tx_digest = *transaction.digest();
-    let tx_position = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN,
-    };
+    let tx_position = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN);
 
     let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
         epoch: 0,
         transaction_digest: tx_digest,
-        transaction_position: tx_position,
+        transaction_position: Some(tx_position),
         // Also test the case where details are not requested.
         include_details: false,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
     })
     .unwrap();
 
@@ -325,6 +444,7 @@ async fn test_wait_for_effects_fastpath_certified() {
         WaitForEffectsResponse::Executed {
             details,
             effects_digest,
+            ..
         } => {
             assert!(details.is_none());
             assert_eq!(effects_digest, exec_effects.digest());
@@ -342,17 +462,16 @@ async fn test_wait_for_effects_finalized() {
 
     let transaction = test_context.build_test_transaction();
     let tx_digest = *transaction.digest();
-    let tx_position = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN,
-    };
+    let tx_position = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN);
 
     let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
         epoch: 0,
         transaction_digest: tx_digest,
-        transaction_position: tx_position,
+        transaction_position: Some(tx_position),
         // Also test the case where details are not requested.
         include_details: false,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
     })
     .unwrap();
 
@@ -389,6 +508,7 @@ async fn test_wait_for_effects_finalized() {
         WaitForEffectsResponse::Executed {
             details,
             effects_digest,
+            ..
         } => {
             assert!(details.is_none());
             assert_eq!(effects_digest, exec_effects.digest());
@@ -403,16 +523,15 @@ async fn test_wait_for_effects_expired() {
 
     let transaction = test_context.build_test_transaction();
     let tx_digest = *transaction.digest();
-    let tx_position = ConsensusTxPosition {
-        block: BlockRef::MIN,
-        index: TransactionIndex::MIN,
-    };
+    let tx_position = ConsensusTxPosition::new(BlockRef::MIN, TransactionIndex::MIN);
 
     let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
         epoch: 0,
         transaction_digest: tx_digest,
-        transaction_position: tx_position,
+        transaction_position: Some(tx_position),
         include_details: true,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
     })
     .unwrap();
 
@@ -424,8 +543,7 @@ async fn test_wait_for_effects_expired() {
             .consensus_tx_status_cache
             .as_ref()
             .unwrap()
-            .update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 1)
-            .await;
+            .update_last_committed_leader_round(CONSENSUS_STATUS_RETENTION_ROUNDS + 1);
         tokio::time::sleep(Duration::from_millis(100)).await;
         epoch_store.set_consensus_tx_status(tx_position, ConsensusTxStatus::Finalized);
         state_clone
@@ -450,3 +568,85 @@ async fn test_wait_for_effects_expired() {
 
     assert!(matches!(response, WaitForEffectsResponse::Expired(_)));
 }
+
+#[tokio::test]
+async fn test_wait_for_effects_wrong_epoch() {
+    let test_context = TestContext::new().await;
+
+    let transaction = test_context.build_test_transaction();
+    let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
+        epoch: 1,
+        transaction_digest: *transaction.digest(),
+        transaction_position: Some(ConsensusTxPosition::new(
+            BlockRef::MIN,
+            TransactionIndex::MIN,
+        )),
+        include_details: false,
+        include_auxiliary_data: false,
+        include_gas_summary: false,
+    })
+    .unwrap();
+
+    let error = test_context
+        .client
+        .wait_for_effects(request, None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        SuiError::WrongEpoch {
+            expected_epoch: 0,
+            actual_epoch: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_wait_for_effects_gas_summary() {
+    let test_context = TestContext::new().await;
+
+    let transaction = test_context.build_test_transaction();
+    let request = RawWaitForEffectsRequest::try_from(WaitForEffectsRequest {
+        epoch: 0,
+        transaction_digest: *transaction.digest(),
+        transaction_position: None,
+        include_details: false,
+        include_auxiliary_data: false,
+        include_gas_summary: true,
+    })
+    .unwrap();
+
+    let epoch_store = test_context.state.epoch_store_for_testing();
+    let (effects, _) = test_context
+        .state
+        .try_execute_immediately(
+            &transaction,
+            None,
+            &epoch_store,
+            SchedulingSource::NonFastPath,
+        )
+        .await
+        .unwrap();
+
+    let response: WaitForEffectsResponse = test_context
+        .client
+        .wait_for_effects(request, None)
+        .await
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    // The gas cost summary is returned without the details of the effects.
+    let WaitForEffectsResponse::Executed {
+        effects_digest,
+        details,
+        gas_cost_summary,
+    } = response
+    else {
+        panic!("Expected Executed response");
+    };
+    assert_eq!(effects_digest, effects.digest());
+    assert!(details.is_none());
+    assert_eq!(gas_cost_summary.as_ref(), Some(effects.gas_cost_summary()));
+}
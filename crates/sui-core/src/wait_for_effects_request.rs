@@ -1,46 +1,280 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use bytes::Bytes;
 use consensus_core::{BlockRef, TransactionIndex};
-use serde::{Deserialize, Serialize};
+use futures::Stream;
+use lru::LruCache;
+use mysten_common::sync::notify_read::NotifyRead;
+use parking_lot::Mutex;
+use prost::Message as _;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use shared_crypto::intent::{Intent, IntentScope};
+use std::{collections::HashMap, future::Future, sync::Arc, time::Duration};
 use sui_types::{
-    committee::EpochId,
+    base_types::{AuthorityName, ObjectRef},
+    committee::{Committee, EpochId},
+    crypto::{AuthoritySignInfo, AuthoritySignInfoTrait},
     digests::{TransactionDigest, TransactionEffectsDigest},
-    effects::{TransactionEffects, TransactionEvents},
-    error::SuiError,
+    effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents},
+    error::{SuiError, SuiResult},
+    execution_status::ExecutionStatus,
+    gas::GasCostSummary,
+    message_envelope::Message,
     messages_consensus::Round,
     messages_grpc::{
-        RawExecutedData, RawExecutedStatus, RawRejectReason, RawRejectedStatus,
-        RawValidatorTransactionStatus, RawWaitForEffectsRequest, RawWaitForEffectsResponse,
+        RawConsensusRejectionCode, RawExecutedData, RawExecutedStatus, RawObjectChunk,
+        RawRejectReason, RawRejectedStatus, RawValidatorTransactionStatus, RawWaitForEffectsChunk,
+        RawWaitForEffectsRequest, RawWaitForEffectsResponse, RawWaitForEffectsResponseChunk,
     },
     object::Object,
 };
+use tokio::sync::broadcast;
+
+/// Maximum number of input or output objects accepted in a single WaitForEffectsResponse.
+/// Responses exceeding this limit are rejected before the objects are deserialized.
+pub const MAX_OBJECTS_PER_RESPONSE: usize = 10_000;
+
+/// Upper bound of the number of transactions in a consensus block, set well above the limit
+/// of any protocol version. Only used to catch positions that cannot be real.
+pub const MAX_TRANSACTIONS_PER_BLOCK: TransactionIndex = 10_000;
 
 /// The position of a transaction in consensus.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub(crate) struct ConsensusTxPosition {
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConsensusTxPosition {
     pub block: BlockRef,
     pub index: TransactionIndex,
 }
 
-pub(crate) struct WaitForEffectsRequest {
+impl ConsensusTxPosition {
+    pub fn new(block: BlockRef, index: TransactionIndex) -> Self {
+        Self { block, index }
+    }
+
+    /// Checks basic invariants of positions of real transactions: round 0 only has genesis
+    /// blocks, which carry no transactions, and blocks are bounded in size.
+    pub fn is_valid(&self) -> bool {
+        self.block.round > 0 && self.index < MAX_TRANSACTIONS_PER_BLOCK
+    }
+
+    /// Returns the name of the authority that proposed the block of this position.
+    /// Unlike the authority index, the name identifies the authority across committees.
+    pub fn authority_name(&self, committee: &Committee) -> SuiResult<AuthorityName> {
+        let index = self.block.author.value() as u32;
+        committee.authority_by_index(index).copied().ok_or_else(|| {
+            SuiError::InvalidCommittee(format!(
+                "Author {index} of position {self} is not in the committee of epoch {}",
+                committee.epoch()
+            ))
+        })
+    }
+}
+
+impl From<ConsensusTxPosition> for BlockRef {
+    fn from(position: ConsensusTxPosition) -> Self {
+        position.block
+    }
+}
+
+/// Converts to the position of the first transaction in the block.
+impl From<BlockRef> for ConsensusTxPosition {
+    fn from(block: BlockRef) -> Self {
+        Self::new(block, 0)
+    }
+}
+
+/// Formats the position as `{round}:{author}:{index}`, e.g. `42:3:7`.
+/// The block digest is omitted to keep log lines short.
+impl std::fmt::Display for ConsensusTxPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.block.round,
+            self.block.author.value(),
+            self.index
+        )
+    }
+}
+
+/// Parses a position formatted by `Display`, e.g. when pasted from logs during debugging.
+/// The string carries no block digest, so the parsed position has the minimum block digest.
+/// The author index is not validated against any committee.
+impl std::str::FromStr for ConsensusTxPosition {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<_> = s.split(':').collect();
+        let [round, author, index] = parts[..] else {
+            anyhow::bail!("Expected position as round:author:index, got {s:?}");
+        };
+        let round: u32 = round.parse()?;
+        let author: u32 = author.parse()?;
+        let index: TransactionIndex = index.parse()?;
+        // AuthorityIndex has no public constructor. Positions are encoded in BCS as
+        // (round, author, digest, index), so decode one with the minimum digest.
+        let digest = BlockRef::MIN.digest;
+        Ok(bcs::from_bytes(&bcs::to_bytes(&(
+            round, author, digest, index,
+        ))?)?)
+    }
+}
+
+/// Positions are ordered by round, block author and transaction index.
+/// The block digest only breaks ties between equivocating blocks, to stay consistent with Eq.
+impl Ord for ConsensusTxPosition {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (
+            self.block.round,
+            self.block.author,
+            self.index,
+            self.block.digest,
+        )
+            .cmp(&(
+                other.block.round,
+                other.block.author,
+                other.index,
+                other.block.digest,
+            ))
+    }
+}
+
+impl PartialOrd for ConsensusTxPosition {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Requests the effects of a transaction.
+///
+/// When the transaction is sequenced by consensus, `transaction_digest` and
+/// `transaction_position` are expected to refer to the same transaction, but this cannot be
+/// verified from the request alone: the position is only a reference to a block, whose content
+/// is not part of the request. Validators therefore never trust the pairing.
+/// Effects are only returned for `transaction_digest`, and only after the transaction at
+/// `transaction_position` is accepted by consensus. A mismatched pair makes the wait time out
+/// or expire instead of returning effects of another transaction.
+///
+/// Without a position, for example for transactions executed from checkpoints, the validator
+/// only waits for the transaction to be executed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WaitForEffectsRequest {
     pub epoch: EpochId,
     pub transaction_digest: TransactionDigest,
-    pub transaction_position: ConsensusTxPosition,
+    pub transaction_position: Option<ConsensusTxPosition>,
     /// Whether to include details of the effects,
     /// including the effects content, events, input objects, and output objects.
     pub include_details: bool,
+    /// Whether to include auxiliary objects in the details, such as the gas object paid by the
+    /// sponsor of the transaction. Requires `include_details`.
+    pub include_auxiliary_data: bool,
+    /// Whether to include the gas cost summary of the effects. Unlike details, the summary is
+    /// small, so it can be requested without the effects content and objects.
+    pub include_gas_summary: bool,
 }
 
-pub(crate) struct ExecutedData {
+/// A placeholder request with a zero digest and a zeroed position, meant to be filled in by
+/// tests. It does not pass `validate()` until a real digest is set.
+impl Default for WaitForEffectsRequest {
+    fn default() -> Self {
+        Self {
+            epoch: 0,
+            transaction_digest: TransactionDigest::ZERO,
+            transaction_position: Some(ConsensusTxPosition::default()),
+            include_details: false,
+            include_auxiliary_data: false,
+            include_gas_summary: false,
+        }
+    }
+}
+
+impl std::fmt::Display for WaitForEffectsRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WaitForEffects(digest={}, epoch={}, ",
+            self.transaction_digest, self.epoch,
+        )?;
+        match &self.transaction_position {
+            Some(position) => write!(f, "position={}:{}", position.block, position.index)?,
+            None => write!(f, "position=none")?,
+        }
+        write!(f, ", details={})", self.include_details)
+    }
+}
+
+/// Builds a WaitForEffectsRequest. Details of the effects are excluded unless requested.
+pub struct WaitForEffectsRequestBuilder {
+    epoch: EpochId,
+    transaction_digest: TransactionDigest,
+    transaction_position: Option<ConsensusTxPosition>,
+    include_details: bool,
+    include_auxiliary_data: bool,
+    include_gas_summary: bool,
+}
+
+impl WaitForEffectsRequestBuilder {
+    pub fn new(
+        epoch: EpochId,
+        transaction_digest: TransactionDigest,
+        transaction_position: Option<ConsensusTxPosition>,
+    ) -> Self {
+        Self {
+            epoch,
+            transaction_digest,
+            transaction_position,
+            include_details: false,
+            include_auxiliary_data: false,
+            include_gas_summary: false,
+        }
+    }
+
+    /// Includes the effects content, events, input objects, and output objects in the response.
+    pub fn with_details(mut self) -> Self {
+        self.include_details = true;
+        self
+    }
+
+    /// Includes details of the effects, together with auxiliary objects such as the gas object.
+    pub fn with_auxiliary_data(mut self) -> Self {
+        self.include_details = true;
+        self.include_auxiliary_data = true;
+        self
+    }
+
+    /// Includes the gas cost summary of the effects, with or without details.
+    pub fn with_gas_summary(mut self) -> Self {
+        self.include_gas_summary = true;
+        self
+    }
+
+    pub fn build(self) -> WaitForEffectsRequest {
+        WaitForEffectsRequest {
+            epoch: self.epoch,
+            transaction_digest: self.transaction_digest,
+            transaction_position: self.transaction_position,
+            include_details: self.include_details,
+            include_auxiliary_data: self.include_auxiliary_data,
+            include_gas_summary: self.include_gas_summary,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutedData {
     pub effects: TransactionEffects,
     pub events: Option<TransactionEvents>,
     pub input_objects: Vec<Object>,
     pub output_objects: Vec<Object>,
+    /// Objects relevant to the transaction that are not part of the regular outputs,
+    /// e.g. the gas object. Only set when auxiliary data is requested.
+    pub auxiliary_objects: Vec<Object>,
+    /// Signature of the responding validator over the effects.
+    pub signature: Option<AuthoritySignInfo>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) enum RejectReason {
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RejectReason {
     // Transaction is not voted to be rejected locally.
     None,
     // Rejected due to lock conflict.
@@ -53,103 +287,721 @@ pub(crate) enum RejectReason {
     CoinDenyList,
 }
 
-pub(crate) enum WaitForEffectsResponse {
+/// Why consensus rejected a transaction, for clients to act on without parsing messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsensusRejectionCode {
+    // Rejected by a quorum of validators when the block is certified on fastpath.
+    FastPath,
+    // Rejected when the consensus commit containing the transaction is processed.
+    PostCommit,
+}
+
+/// Cloning a response is a deep copy, including the effects and objects of its details.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaitForEffectsResponse {
     Executed {
         effects_digest: TransactionEffectsDigest,
         details: Option<Box<ExecutedData>>,
+        // Set when the gas cost summary is requested, with or without details.
+        gas_cost_summary: Option<GasCostSummary>,
     },
     Rejected {
         // The rejection reason known locally.
         reason: RejectReason,
+        // How consensus rejected the transaction. Not set by validators that predate it.
+        consensus_code: Option<ConsensusRejectionCode>,
     },
     // The transaction position is expired at the committed round.
     Expired(Round),
 }
 
-impl TryFrom<RawWaitForEffectsRequest> for WaitForEffectsRequest {
-    type Error = SuiError;
-
-    fn try_from(value: RawWaitForEffectsRequest) -> Result<Self, Self::Error> {
-        let transaction_digest = bcs::from_bytes(&value.transaction_digest).map_err(|err| {
-            SuiError::GrpcMessageDeserializeError {
+impl WaitForEffectsRequest {
+    /// Checks the parts of the request that can be validated without consensus state.
+    /// Should be called after deserializing a request from the network.
+    pub fn validate(&self) -> SuiResult {
+        if self.transaction_digest == TransactionDigest::ZERO {
+            return Err(SuiError::GrpcMessageDeserializeError {
                 type_info: "RawWaitForEffectsRequest.transaction_digest".to_string(),
-                error: err.to_string(),
+                error: "transaction digest is unset".to_string(),
+            });
+        }
+        if self.include_auxiliary_data && !self.include_details {
+            return Err(SuiError::GrpcMessageDeserializeError {
+                type_info: "RawWaitForEffectsRequest.include_auxiliary_data".to_string(),
+                error: "auxiliary data requires details to be included".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Serializes the request to JSON, for tooling that does not speak BCS or gRPC.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Only the transaction and its position are hashed. Requests for the same transaction that
+/// ask for different details land in the same bucket, but are still not equal.
+impl std::hash::Hash for WaitForEffectsRequest {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.transaction_digest.hash(state);
+        self.transaction_position.hash(state);
+    }
+}
+
+/// Shares the work of identical in-flight WaitForEffectsRequests, e.g. from many clients
+/// watching the same hot transaction. The first caller computes the response, and the result
+/// is broadcast to all callers that arrive while it is in flight.
+#[derive(Default)]
+pub struct WaitForEffectsDispatcher {
+    in_flight: Mutex<
+        HashMap<WaitForEffectsRequest, broadcast::Sender<SuiResult<RawWaitForEffectsResponse>>>,
+    >,
+}
+
+impl WaitForEffectsDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the response of `request`, computed by `wait` unless an identical request is
+    /// already in flight. If the caller computing the response is cancelled, one of the
+    /// remaining callers takes over.
+    pub async fn dispatch<F, Fut>(
+        &self,
+        request: &WaitForEffectsRequest,
+        wait: F,
+    ) -> SuiResult<RawWaitForEffectsResponse>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = SuiResult<RawWaitForEffectsResponse>>,
+    {
+        let mut wait = Some(wait);
+        loop {
+            let mut rx = {
+                let mut in_flight = self.in_flight.lock();
+                match in_flight.get(request) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        let (tx, _) = broadcast::channel(1);
+                        in_flight.insert(request.clone(), tx.clone());
+                        drop(in_flight);
+                        let wait = wait.take().expect("Response is only computed once");
+                        let result = {
+                            // Callers arriving after the response is computed compute a new
+                            // one, as the transaction status may have changed.
+                            let _guard = DispatchGuard {
+                                dispatcher: self,
+                                request,
+                            };
+                            wait().await
+                        };
+                        // Sending fails when there are no other callers.
+                        let _ = tx.send(result.clone());
+                        return result;
+                    }
+                }
+            };
+            match rx.recv().await {
+                Ok(result) => return result,
+                // The caller computing the response was cancelled.
+                Err(_) => continue,
             }
-        })?;
-        let transaction_position = bcs::from_bytes(&value.transaction_position).map_err(|err| {
-            SuiError::GrpcMessageDeserializeError {
-                type_info: "RawWaitForEffectsRequest.transaction_position".to_string(),
-                error: err.to_string(),
+        }
+    }
+
+    pub fn num_in_flight(&self) -> usize {
+        self.in_flight.lock().len()
+    }
+}
+
+/// Stops tracking a request of a WaitForEffectsDispatcher when its computation finishes or is
+/// dropped. When dropped, the broadcast channel is closed, which wakes up the other callers.
+struct DispatchGuard<'a> {
+    dispatcher: &'a WaitForEffectsDispatcher,
+    request: &'a WaitForEffectsRequest,
+}
+
+impl Drop for DispatchGuard<'_> {
+    fn drop(&mut self) {
+        self.dispatcher.in_flight.lock().remove(self.request);
+    }
+}
+
+/// Maximum number of input and output objects in each object chunk of a streamed response.
+pub const OBJECTS_PER_RESPONSE_CHUNK: usize = 100;
+
+/// Splits a response into chunks for streaming. The first chunk is the response without its
+/// input and output objects, followed by chunks of at most `objects_per_chunk` objects.
+/// Object chunks are encoded as the stream is polled, so the first chunk is sent without
+/// waiting for all objects to be encoded. Responses without details are sent as a single chunk.
+pub fn response_chunk_stream(
+    mut response: WaitForEffectsResponse,
+    objects_per_chunk: usize,
+) -> SuiResult<impl Stream<Item = SuiResult<RawWaitForEffectsResponseChunk>> + Send + 'static> {
+    assert!(objects_per_chunk > 0);
+    let (input_objects, output_objects) = match &mut response {
+        WaitForEffectsResponse::Executed {
+            details: Some(details),
+            ..
+        } => (
+            std::mem::take(&mut details.input_objects),
+            std::mem::take(&mut details.output_objects),
+        ),
+        _ => (vec![], vec![]),
+    };
+    let effects_chunk = RawWaitForEffectsResponseChunk {
+        inner: Some(RawWaitForEffectsChunk::EffectsChunk(response.try_into()?)),
+    };
+    let object_chunks = into_chunks(input_objects, objects_per_chunk)
+        .map(|objects| (objects, vec![]))
+        .chain(into_chunks(output_objects, objects_per_chunk).map(|objects| (vec![], objects)))
+        .map(|(input_objects, output_objects)| {
+            let chunk = RawObjectChunk {
+                input_objects: encode_objects::<BcsCodec>(
+                    "RawWaitForEffectsResponse.input_objects",
+                    input_objects,
+                )?,
+                output_objects: encode_objects::<BcsCodec>(
+                    "RawWaitForEffectsResponse.output_objects",
+                    output_objects,
+                )?,
+            };
+            Ok(RawWaitForEffectsResponseChunk {
+                inner: Some(RawWaitForEffectsChunk::ObjectChunk(chunk)),
+            })
+        });
+    Ok(futures::stream::iter(
+        std::iter::once(Ok(effects_chunk)).chain(object_chunks),
+    ))
+}
+
+fn into_chunks<T>(items: Vec<T>, chunk_size: usize) -> impl Iterator<Item = Vec<T>> {
+    let mut items = items.into_iter();
+    std::iter::from_fn(move || {
+        let chunk: Vec<_> = items.by_ref().take(chunk_size).collect();
+        (!chunk.is_empty()).then_some(chunk)
+    })
+}
+
+/// Reassembles a response from the chunks of a streamed response, in the order received.
+pub fn assemble_response_from_chunks(
+    chunks: impl IntoIterator<Item = RawWaitForEffectsResponseChunk>,
+) -> SuiResult<RawWaitForEffectsResponse> {
+    let error = |error: &str| SuiError::GrpcMessageDeserializeError {
+        type_info: "RawWaitForEffectsResponseChunk".to_string(),
+        error: error.to_string(),
+    };
+    let mut chunks = chunks.into_iter();
+    let Some(RawWaitForEffectsChunk::EffectsChunk(mut response)) =
+        chunks.next().and_then(|chunk| chunk.inner)
+    else {
+        return Err(error("First chunk must be an effects chunk"));
+    };
+    for chunk in chunks {
+        let Some(RawWaitForEffectsChunk::ObjectChunk(objects)) = chunk.inner else {
+            return Err(error("Only the first chunk can be an effects chunk"));
+        };
+        let Some(RawValidatorTransactionStatus::Executed(RawExecutedStatus {
+            details: Some(details),
+            ..
+        })) = response.inner.as_mut()
+        else {
+            return Err(error(
+                "Object chunks require an executed response with details",
+            ));
+        };
+        if details.input_objects.len() + objects.input_objects.len() > MAX_OBJECTS_PER_RESPONSE
+            || details.output_objects.len() + objects.output_objects.len()
+                > MAX_OBJECTS_PER_RESPONSE
+        {
+            return Err(error("Too many objects in streamed response"));
+        }
+        details.input_objects.extend(objects.input_objects);
+        details.output_objects.extend(objects.output_objects);
+    }
+    Ok(response)
+}
+
+/// Default capacity of a WaitForEffectsResponseCache, in bytes of encoded responses.
+pub const WAIT_FOR_EFFECTS_RESPONSE_CACHE_SIZE_BYTES: usize = 64 << 20;
+
+/// Identifies the encoded response of an executed transaction.
+/// Requests for the same transaction with different details get different responses, and
+/// responses are signed for the epoch of the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WaitForEffectsResponseCacheKey {
+    pub epoch: EpochId,
+    pub transaction_digest: TransactionDigest,
+    pub include_details: bool,
+    pub include_auxiliary_data: bool,
+    pub include_gas_summary: bool,
+}
+
+impl From<&WaitForEffectsRequest> for WaitForEffectsResponseCacheKey {
+    fn from(request: &WaitForEffectsRequest) -> Self {
+        Self {
+            epoch: request.epoch,
+            transaction_digest: request.transaction_digest,
+            include_details: request.include_details,
+            include_auxiliary_data: request.include_auxiliary_data,
+            include_gas_summary: request.include_gas_summary,
+        }
+    }
+}
+
+/// LRU cache of encoded responses of executed transactions, so that responses requested by
+/// many clients are only serialized once. Bounded by the total encoded size of the responses.
+///
+/// Only responses built from final executed effects should be cached. Responses built from
+/// fastpath outputs can be invalidated by a post-commit rejection.
+pub struct WaitForEffectsResponseCache {
+    capacity_bytes: usize,
+    inner: Mutex<ResponseCacheInner>,
+}
+
+struct ResponseCacheInner {
+    responses: LruCache<WaitForEffectsResponseCacheKey, RawWaitForEffectsResponse>,
+    size_bytes: usize,
+}
+
+impl WaitForEffectsResponseCache {
+    pub fn new(capacity_bytes: usize) -> Self {
+        Self {
+            capacity_bytes,
+            inner: Mutex::new(ResponseCacheInner {
+                responses: LruCache::unbounded(),
+                size_bytes: 0,
+            }),
+        }
+    }
+
+    /// Returns the cached response and marks it as most recently used.
+    /// Cloning the response only clones reference counted buffers.
+    pub fn get(&self, key: &WaitForEffectsResponseCacheKey) -> Option<RawWaitForEffectsResponse> {
+        self.inner.lock().responses.get(key).cloned()
+    }
+
+    /// Caches the response, evicting the least recently used responses until the cache fits
+    /// within its capacity. Responses larger than the whole capacity are not cached.
+    pub fn insert(&self, key: WaitForEffectsResponseCacheKey, response: RawWaitForEffectsResponse) {
+        let size = response.encoded_len();
+        if size > self.capacity_bytes {
+            return;
+        }
+        let mut inner = self.inner.lock();
+        if let Some(old) = inner.responses.put(key, response) {
+            inner.size_bytes -= old.encoded_len();
+        }
+        inner.size_bytes += size;
+        while inner.size_bytes > self.capacity_bytes {
+            let (_, evicted) = inner
+                .responses
+                .pop_lru()
+                .expect("Cache exceeding its capacity should not be empty");
+            inner.size_bytes -= evicted.encoded_len();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().responses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Total encoded size of the cached responses.
+    pub fn size_bytes(&self) -> usize {
+        self.inner.lock().size_bytes
+    }
+}
+
+impl WaitForEffectsResponse {
+    /// Serializes the response to JSON, for tooling that does not speak BCS or gRPC.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns true if the response includes at least one event of the executed transaction.
+    pub fn has_events(&self) -> bool {
+        self.details().is_some_and(|details| {
+            details
+                .events
+                .as_ref()
+                .is_some_and(|events| !events.data.is_empty())
+        })
+    }
+
+    /// Returns true if the response includes at least one input object.
+    pub fn has_input_objects(&self) -> bool {
+        self.details()
+            .is_some_and(|details| !details.input_objects.is_empty())
+    }
+
+    /// Returns true if the response includes at least one output object.
+    pub fn has_output_objects(&self) -> bool {
+        self.details()
+            .is_some_and(|details| !details.output_objects.is_empty())
+    }
+
+    /// Returns true if the transaction was executed and the response carries nothing beyond
+    /// the effects: no events, input, output or auxiliary objects.
+    pub fn is_minimal(&self) -> bool {
+        match self {
+            WaitForEffectsResponse::Executed { details, .. } => {
+                details.as_ref().is_none_or(|details| {
+                    !self.has_events()
+                        && details.input_objects.is_empty()
+                        && details.output_objects.is_empty()
+                        && details.auxiliary_objects.is_empty()
+                })
             }
-        })?;
+            _ => false,
+        }
+    }
+
+    fn details(&self) -> Option<&ExecutedData> {
+        match self {
+            WaitForEffectsResponse::Executed { details, .. } => details.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Checks that the effects of an executed response match its effects digest, which is
+    /// computed by the server. Unlike `verify()`, this does not check any signature.
+    /// Responses without effects have nothing to check and are accepted as is.
+    pub fn verify_digest(&self) -> SuiResult {
+        let WaitForEffectsResponse::Executed {
+            effects_digest,
+            details: Some(details),
+            ..
+        } = self
+        else {
+            return Ok(());
+        };
+        let actual_digest = details.effects.digest();
+        if actual_digest != *effects_digest {
+            return Err(SuiError::GrpcMessageDeserializeError {
+                type_info: "RawExecutedStatus.effects_digest".to_string(),
+                error: format!(
+                    "Effects digest {actual_digest} does not match response digest {effects_digest}"
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies that the effects of an executed response are signed by a validator of
+    /// `committee`, and that they match the effects digest of the response.
+    /// Only executed responses with details carry a signature, and only once the validator has
+    /// signed the effects for a certificate. Rejected and expired responses are never signed,
+    /// so they cannot be authenticated and fail verification.
+    pub fn verify(&self, committee: &Committee) -> SuiResult<()> {
+        let WaitForEffectsResponse::Executed { details, .. } = self else {
+            return Err(SuiError::InvalidSignature {
+                error: "Rejected and expired responses are not signed".to_string(),
+            });
+        };
+        let Some(details) = details else {
+            return Err(SuiError::InvalidSignature {
+                error: "Executed response without details cannot be verified".to_string(),
+            });
+        };
+        let Some(signature) = &details.signature else {
+            return Err(SuiError::InvalidSignature {
+                error: "Executed response is not signed".to_string(),
+            });
+        };
+        self.verify_digest()?;
+        signature.verify_secure(
+            &details.effects,
+            Intent::sui_app(IntentScope::TransactionEffects),
+            committee,
+        )
+    }
+}
+
+/// A summary of WaitForEffectsResponse for JSON-RPC consumers, with objects reduced to their
+/// references and effects reduced to their outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaitForEffectsSummary {
+    Executed {
+        effects_digest: TransactionEffectsDigest,
+        details: Option<ExecutedDataSummary>,
+    },
+    Rejected {
+        reason: RejectReason,
+        consensus_code: Option<ConsensusRejectionCode>,
+    },
+    Expired(Round),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutedDataSummary {
+    pub effects: EffectsSummary,
+    pub num_events: usize,
+    pub input_objects: Vec<ObjectRef>,
+    pub output_objects: Vec<ObjectRef>,
+    pub auxiliary_objects: Vec<ObjectRef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectsSummary {
+    pub transaction_digest: TransactionDigest,
+    pub executed_epoch: EpochId,
+    pub status: ExecutionStatus,
+    pub gas_cost_summary: GasCostSummary,
+}
+
+impl From<WaitForEffectsResponse> for WaitForEffectsSummary {
+    fn from(response: WaitForEffectsResponse) -> Self {
+        match response {
+            WaitForEffectsResponse::Executed {
+                effects_digest,
+                details,
+                ..
+            } => Self::Executed {
+                effects_digest,
+                details: details.map(|details| ExecutedDataSummary {
+                    effects: EffectsSummary {
+                        transaction_digest: *details.effects.transaction_digest(),
+                        executed_epoch: details.effects.executed_epoch(),
+                        status: details.effects.status().clone(),
+                        gas_cost_summary: details.effects.gas_cost_summary().clone(),
+                    },
+                    num_events: details.events.map_or(0, |events| events.data.len()),
+                    input_objects: details
+                        .input_objects
+                        .iter()
+                        .map(Object::compute_object_reference)
+                        .collect(),
+                    output_objects: details
+                        .output_objects
+                        .iter()
+                        .map(Object::compute_object_reference)
+                        .collect(),
+                    auxiliary_objects: details
+                        .auxiliary_objects
+                        .iter()
+                        .map(Object::compute_object_reference)
+                        .collect(),
+                }),
+            },
+            WaitForEffectsResponse::Rejected {
+                reason,
+                consensus_code,
+            } => Self::Rejected {
+                reason,
+                consensus_code,
+            },
+            WaitForEffectsResponse::Expired(round) => Self::Expired(round),
+        }
+    }
+}
+
+/// Serialization format of the byte fields in raw WaitForEffects messages, e.g. digests,
+/// effects and objects. Validators use `BcsCodec`, which the `TryFrom` conversions default to.
+pub trait Codec {
+    type Error: std::fmt::Display;
+
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Self::Error>;
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error>;
+}
+
+pub struct BcsCodec;
+
+impl Codec for BcsCodec {
+    type Error = bcs::Error;
+
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Self::Error> {
+        bcs::to_bytes(value)
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error> {
+        bcs::from_bytes(bytes)
+    }
+}
+
+/// Encodes fields as JSON, for clients that cannot decode BCS, e.g. in WASM.
+pub struct JsonCodec;
+
+impl Codec for JsonCodec {
+    type Error = serde_json::Error;
+
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(value)
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error> {
+        serde_json::from_slice(bytes)
+    }
+}
+
+fn decode_field<C: Codec, T: DeserializeOwned>(type_info: &str, bytes: &[u8]) -> SuiResult<T> {
+    C::decode(bytes).map_err(|err| SuiError::GrpcMessageDeserializeError {
+        type_info: type_info.to_string(),
+        error: err.to_string(),
+    })
+}
+
+fn encode_field<C: Codec, T: Serialize>(type_info: &str, value: &T) -> SuiResult<Bytes> {
+    C::encode(value)
+        .map(Bytes::from)
+        .map_err(|err| SuiError::GrpcMessageSerializeError {
+            type_info: type_info.to_string(),
+            error: err.to_string(),
+        })
+}
+
+impl WaitForEffectsRequest {
+    /// Decodes a raw request whose fields are encoded with `C`.
+    pub fn from_raw<C: Codec>(value: RawWaitForEffectsRequest) -> SuiResult<Self> {
+        let transaction_digest = decode_field::<C, _>(
+            "RawWaitForEffectsRequest.transaction_digest",
+            &value.transaction_digest,
+        )?;
+        let transaction_position = value
+            .transaction_position
+            .map(|position| {
+                decode_field::<C, _>("RawWaitForEffectsRequest.transaction_position", &position)
+            })
+            .transpose()?;
         Ok(Self {
             epoch: value.epoch,
             transaction_digest,
             transaction_position,
             include_details: value.include_details,
+            include_auxiliary_data: value.include_auxiliary_data,
+            include_gas_summary: value.include_gas_summary,
+        })
+    }
+
+    /// Encodes the fields of the request with `C`.
+    pub fn into_raw<C: Codec>(self) -> SuiResult<RawWaitForEffectsRequest> {
+        let transaction_digest = encode_field::<C, _>(
+            "RawWaitForEffectsRequest.transaction_digest",
+            &self.transaction_digest,
+        )?;
+        let transaction_position = self
+            .transaction_position
+            .map(|position| {
+                encode_field::<C, _>("RawWaitForEffectsRequest.transaction_position", &position)
+            })
+            .transpose()?;
+        Ok(RawWaitForEffectsRequest {
+            epoch: self.epoch,
+            transaction_digest,
+            transaction_position,
+            include_details: self.include_details,
+            include_auxiliary_data: self.include_auxiliary_data,
+            include_gas_summary: self.include_gas_summary,
         })
     }
 }
 
-impl TryFrom<RawWaitForEffectsResponse> for WaitForEffectsResponse {
+impl TryFrom<RawWaitForEffectsRequest> for WaitForEffectsRequest {
     type Error = SuiError;
 
-    fn try_from(value: RawWaitForEffectsResponse) -> Result<Self, Self::Error> {
+    fn try_from(value: RawWaitForEffectsRequest) -> Result<Self, Self::Error> {
+        Self::from_raw::<BcsCodec>(value)
+    }
+}
+
+impl TryFrom<WaitForEffectsRequest> for RawWaitForEffectsRequest {
+    type Error = SuiError;
+
+    fn try_from(value: WaitForEffectsRequest) -> Result<Self, Self::Error> {
+        value.into_raw::<BcsCodec>()
+    }
+}
+
+impl WaitForEffectsResponse {
+    /// Decodes a raw response whose fields are encoded with `C`.
+    pub fn from_raw<C: Codec>(value: RawWaitForEffectsResponse) -> SuiResult<Self> {
         match value.inner {
             Some(RawValidatorTransactionStatus::Executed(executed)) => {
-                let effects_digest = bcs::from_bytes(&executed.effects_digest).map_err(|err| {
-                    SuiError::GrpcMessageDeserializeError {
-                        type_info: "RawWaitForEffectsResponse.effects_digest".to_string(),
-                        error: err.to_string(),
-                    }
-                })?;
+                let effects_digest = decode_field::<C, _>(
+                    "RawWaitForEffectsResponse.effects_digest",
+                    &executed.effects_digest,
+                )?;
                 let details = if let Some(details) = executed.details {
-                    let effects = bcs::from_bytes(&details.effects).map_err(|err| {
-                        SuiError::GrpcMessageDeserializeError {
-                            type_info: "RawWaitForEffectsResponse.details.effects".to_string(),
-                            error: err.to_string(),
-                        }
-                    })?;
-                    let events = if let Some(events) = details.events {
-                        Some(bcs::from_bytes(&events).map_err(|err| {
-                            SuiError::GrpcMessageDeserializeError {
-                                type_info: "RawWaitForEffectsResponse.details.events".to_string(),
-                                error: err.to_string(),
-                            }
-                        })?)
-                    } else {
-                        None
-                    };
-                    let mut input_objects = Vec::with_capacity(details.input_objects.len());
-                    for object in details.input_objects {
-                        input_objects.push(bcs::from_bytes(&object).map_err(|err| {
-                            SuiError::GrpcMessageDeserializeError {
-                                type_info: "RawWaitForEffectsResponse.input_objects".to_string(),
-                                error: err.to_string(),
-                            }
-                        })?);
-                    }
-                    let mut output_objects = Vec::with_capacity(details.output_objects.len());
-                    for object in details.output_objects {
-                        output_objects.push(bcs::from_bytes(&object).map_err(|err| {
-                            SuiError::GrpcMessageDeserializeError {
-                                type_info: "RawWaitForEffectsResponse.output_objects".to_string(),
-                                error: err.to_string(),
-                            }
-                        })?);
-                    }
+                    check_objects_count(
+                        "RawWaitForEffectsResponse.input_objects",
+                        details.input_objects.len(),
+                    )?;
+                    check_objects_count(
+                        "RawWaitForEffectsResponse.output_objects",
+                        details.output_objects.len(),
+                    )?;
+                    check_objects_count(
+                        "RawWaitForEffectsResponse.auxiliary_objects",
+                        details.auxiliary_objects.len(),
+                    )?;
+                    let effects = decode_field::<C, _>(
+                        "RawWaitForEffectsResponse.details.effects",
+                        &details.effects,
+                    )?;
+                    let events = details
+                        .events
+                        .map(|events| {
+                            decode_field::<C, _>(
+                                "RawWaitForEffectsResponse.details.events",
+                                &events,
+                            )
+                        })
+                        .transpose()?;
+                    let input_objects = decode_objects::<C>(
+                        "RawWaitForEffectsResponse.input_objects",
+                        details.input_objects,
+                    )?;
+                    let output_objects = decode_objects::<C>(
+                        "RawWaitForEffectsResponse.output_objects",
+                        details.output_objects,
+                    )?;
+                    let auxiliary_objects = decode_objects::<C>(
+                        "RawWaitForEffectsResponse.auxiliary_objects",
+                        details.auxiliary_objects,
+                    )?;
+                    let signature = details
+                        .signature
+                        .map(|signature| {
+                            decode_field::<C, _>(
+                                "RawWaitForEffectsResponse.details.signature",
+                                &signature,
+                            )
+                        })
+                        .transpose()?;
                     Some(Box::new(ExecutedData {
                         effects,
                         events,
                         input_objects,
                         output_objects,
+                        auxiliary_objects,
+                        signature,
                     }))
                 } else {
                     None
                 };
+                let gas_cost_summary = executed
+                    .gas_cost_summary
+                    .map(|summary| {
+                        decode_field::<C, _>("RawWaitForEffectsResponse.gas_cost_summary", &summary)
+                    })
+                    .transpose()?;
                 Ok(Self::Executed {
                     effects_digest,
                     details,
+                    gas_cost_summary,
                 })
             }
             Some(RawValidatorTransactionStatus::Rejected(rejected)) => {
@@ -172,7 +1024,19 @@ impl TryFrom<RawWaitForEffectsResponse> for WaitForEffectsResponse {
                     }
                     RawRejectReason::CoinDenyList => RejectReason::CoinDenyList,
                 };
-                Ok(Self::Rejected { reason })
+                // Codes added by newer validators are unknown here. They are dropped, so that
+                // the rest of the response can still be used.
+                let consensus_code = rejected
+                    .consensus_code
+                    .and_then(|code| RawConsensusRejectionCode::try_from(code).ok())
+                    .map(|code| match code {
+                        RawConsensusRejectionCode::FastPath => ConsensusRejectionCode::FastPath,
+                        RawConsensusRejectionCode::PostCommit => ConsensusRejectionCode::PostCommit,
+                    });
+                Ok(Self::Rejected {
+                    reason,
+                    consensus_code,
+                })
             }
             Some(RawValidatorTransactionStatus::Expired(round)) => Ok(Self::Expired(round)),
             None => Err(SuiError::GrpcMessageDeserializeError {
@@ -181,107 +1045,79 @@ impl TryFrom<RawWaitForEffectsResponse> for WaitForEffectsResponse {
             }),
         }
     }
-}
 
-impl TryFrom<WaitForEffectsRequest> for RawWaitForEffectsRequest {
-    type Error = SuiError;
-
-    fn try_from(value: WaitForEffectsRequest) -> Result<Self, Self::Error> {
-        let transaction_digest = bcs::to_bytes(&value.transaction_digest)
-            .map_err(|err| SuiError::GrpcMessageSerializeError {
-                type_info: "RawWaitForEffectsRequest.transaction_digest".to_string(),
-                error: err.to_string(),
-            })?
-            .into();
-        let transaction_position = bcs::to_bytes(&value.transaction_position)
-            .map_err(|err| SuiError::GrpcMessageSerializeError {
-                type_info: "RawWaitForEffectsRequest.transaction_position".to_string(),
-                error: err.to_string(),
-            })?
-            .into();
-        Ok(Self {
-            epoch: value.epoch,
-            transaction_digest,
-            transaction_position,
-            include_details: value.include_details,
-        })
-    }
-}
-
-impl TryFrom<WaitForEffectsResponse> for RawWaitForEffectsResponse {
-    type Error = SuiError;
-
-    fn try_from(value: WaitForEffectsResponse) -> Result<Self, Self::Error> {
-        let inner = match value {
+    /// Encodes the fields of the response with `C`.
+    pub fn into_raw<C: Codec>(self) -> SuiResult<RawWaitForEffectsResponse> {
+        let inner = match self {
             WaitForEffectsResponse::Executed {
                 effects_digest,
                 details,
+                gas_cost_summary,
             } => {
-                let effects_digest = bcs::to_bytes(&effects_digest)
-                    .map_err(|err| SuiError::GrpcMessageSerializeError {
-                        type_info: "RawWaitForEffectsResponse.effects_digest".to_string(),
-                        error: err.to_string(),
-                    })?
-                    .into();
+                let effects_digest = encode_field::<C, _>(
+                    "RawWaitForEffectsResponse.effects_digest",
+                    &effects_digest,
+                )?;
                 let details = if let Some(details) = details {
-                    let effects = bcs::to_bytes(&details.effects)
-                        .map_err(|err| SuiError::GrpcMessageSerializeError {
-                            type_info: "RawWaitForEffectsResponse.details.effects".to_string(),
-                            error: err.to_string(),
-                        })?
-                        .into();
-                    let events = if let Some(events) = &details.events {
-                        Some(
-                            bcs::to_bytes(events)
-                                .map_err(|err| SuiError::GrpcMessageSerializeError {
-                                    type_info: "RawWaitForEffectsResponse.details.events"
-                                        .to_string(),
-                                    error: err.to_string(),
-                                })?
-                                .into(),
-                        )
-                    } else {
-                        None
-                    };
-                    let mut input_objects = Vec::with_capacity(details.input_objects.len());
-                    for object in details.input_objects {
-                        input_objects.push(
-                            bcs::to_bytes(&object)
-                                .map_err(|err| SuiError::GrpcMessageSerializeError {
-                                    type_info: "RawWaitForEffectsResponse.input_objects"
-                                        .to_string(),
-                                    error: err.to_string(),
-                                })?
-                                .into(),
-                        );
-                    }
-                    let mut output_objects = Vec::with_capacity(details.output_objects.len());
-                    for object in details.output_objects {
-                        output_objects.push(
-                            bcs::to_bytes(&object)
-                                .map_err(|err| SuiError::GrpcMessageSerializeError {
-                                    type_info: "RawWaitForEffectsResponse.output_objects"
-                                        .to_string(),
-                                    error: err.to_string(),
-                                })?
-                                .into(),
-                        );
-                    }
+                    let effects = encode_field::<C, _>(
+                        "RawWaitForEffectsResponse.details.effects",
+                        &details.effects,
+                    )?;
+                    let events = details
+                        .events
+                        .as_ref()
+                        .map(|events| {
+                            encode_field::<C, _>("RawWaitForEffectsResponse.details.events", events)
+                        })
+                        .transpose()?;
+                    let input_objects = encode_objects::<C>(
+                        "RawWaitForEffectsResponse.input_objects",
+                        details.input_objects,
+                    )?;
+                    let output_objects = encode_objects::<C>(
+                        "RawWaitForEffectsResponse.output_objects",
+                        details.output_objects,
+                    )?;
+                    let auxiliary_objects = encode_objects::<C>(
+                        "RawWaitForEffectsResponse.auxiliary_objects",
+                        details.auxiliary_objects,
+                    )?;
+                    let signature = details
+                        .signature
+                        .as_ref()
+                        .map(|signature| {
+                            encode_field::<C, _>(
+                                "RawWaitForEffectsResponse.details.signature",
+                                signature,
+                            )
+                        })
+                        .transpose()?;
                     Some(RawExecutedData {
                         effects,
                         events,
                         input_objects,
                         output_objects,
+                        auxiliary_objects,
+                        signature,
                     })
                 } else {
                     None
                 };
+                let gas_cost_summary = gas_cost_summary
+                    .map(|summary| {
+                        encode_field::<C, _>("RawWaitForEffectsResponse.gas_cost_summary", &summary)
+                    })
+                    .transpose()?;
                 RawValidatorTransactionStatus::Executed(RawExecutedStatus {
                     effects_digest,
                     details,
+                    gas_cost_summary,
                 })
             }
-            WaitForEffectsResponse::Rejected { reason } => {
+            WaitForEffectsResponse::Rejected {
+                reason,
+                consensus_code,
+            } => {
                 let (reason, message) = match reason {
                     RejectReason::None => (RawRejectReason::None, None),
                     RejectReason::LockConflict(message) => {
@@ -293,9 +1129,16 @@ impl TryFrom<WaitForEffectsResponse> for RawWaitForEffectsResponse {
                     RejectReason::Overload(message) => (RawRejectReason::Overload, Some(message)),
                     RejectReason::CoinDenyList => (RawRejectReason::CoinDenyList, None),
                 };
+                let consensus_code = consensus_code.map(|code| match code {
+                    ConsensusRejectionCode::FastPath => RawConsensusRejectionCode::FastPath as i32,
+                    ConsensusRejectionCode::PostCommit => {
+                        RawConsensusRejectionCode::PostCommit as i32
+                    }
+                });
                 RawValidatorTransactionStatus::Rejected(RawRejectedStatus {
                     reason: reason as i32,
                     message,
+                    consensus_code,
                 })
             }
             WaitForEffectsResponse::Expired(round) => RawValidatorTransactionStatus::Expired(round),
@@ -303,3 +1146,1165 @@ impl TryFrom<WaitForEffectsResponse> for RawWaitForEffectsResponse {
         Ok(RawWaitForEffectsResponse { inner: Some(inner) })
     }
 }
+
+impl TryFrom<RawWaitForEffectsResponse> for WaitForEffectsResponse {
+    type Error = SuiError;
+
+    fn try_from(value: RawWaitForEffectsResponse) -> Result<Self, Self::Error> {
+        Self::from_raw::<BcsCodec>(value)
+    }
+}
+
+impl TryFrom<WaitForEffectsResponse> for RawWaitForEffectsResponse {
+    type Error = SuiError;
+
+    fn try_from(value: WaitForEffectsResponse) -> Result<Self, Self::Error> {
+        value.into_raw::<BcsCodec>()
+    }
+}
+
+/// Waits until a response for the transaction is published to `notify`, e.g. by a task reading
+/// a stream of validator responses, and decodes it.
+/// Returns `SuiError::TimeoutError` if no response arrives within `timeout`.
+pub async fn wait_for_effects(
+    digest: &TransactionDigest,
+    timeout: Duration,
+    notify: Arc<NotifyRead<TransactionDigest, RawWaitForEffectsResponse>>,
+) -> SuiResult<WaitForEffectsResponse> {
+    let raw = tokio::time::timeout(timeout, notify.register_one(digest))
+        .await
+        .map_err(|_| SuiError::TimeoutError)?;
+    WaitForEffectsResponse::try_from(raw)
+}
+
+fn check_objects_count(type_info: &str, count: usize) -> SuiResult {
+    if count > MAX_OBJECTS_PER_RESPONSE {
+        return Err(SuiError::GrpcMessageDeserializeError {
+            type_info: type_info.to_string(),
+            error: format!(
+                "Number of objects {} exceeds the limit {}",
+                count, MAX_OBJECTS_PER_RESPONSE
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn decode_objects<C: Codec>(type_info: &str, objects: Vec<Bytes>) -> SuiResult<Vec<Object>> {
+    objects
+        .iter()
+        .map(|object| decode_field::<C, _>(type_info, object))
+        .collect()
+}
+
+fn encode_objects<C: Codec>(type_info: &str, objects: Vec<Object>) -> SuiResult<Vec<Bytes>> {
+    objects
+        .iter()
+        .map(|object| encode_field::<C, _>(type_info, object))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use sui_types::crypto::{get_key_pair, AuthorityKeyPair, KeypairTraits};
+    use sui_types::event::Event;
+
+    fn test_position() -> ConsensusTxPosition {
+        ConsensusTxPosition::new(BlockRef::MIN, 3)
+    }
+
+    #[test]
+    fn test_position_display_roundtrip() {
+        let position = ConsensusTxPosition::new(
+            BlockRef {
+                round: 42,
+                author: consensus_config::AuthorityIndex::new_for_test(3),
+                digest: BlockRef::MIN.digest,
+            },
+            7,
+        );
+        assert_eq!(position.to_string(), "42:3:7");
+        assert_eq!("42:3:7".parse::<ConsensusTxPosition>().unwrap(), position);
+
+        for invalid in ["", "42:3", "42:3:7:1", "a:3:7", "42:3:70000", "-1:3:7"] {
+            assert!(invalid.parse::<ConsensusTxPosition>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_position_is_valid() {
+        let position = |s: &str| s.parse::<ConsensusTxPosition>().unwrap();
+        assert!(position("1:0:0").is_valid());
+        assert!(position(&format!("1:0:{}", MAX_TRANSACTIONS_PER_BLOCK - 1)).is_valid());
+        // Genesis blocks have no transactions.
+        assert!(!position("0:0:0").is_valid());
+        assert!(!position(&format!("1:0:{MAX_TRANSACTIONS_PER_BLOCK}")).is_valid());
+    }
+
+    #[test]
+    fn test_position_authority_name() {
+        let (committee, _) = Committee::new_simple_test_committee();
+        let position: ConsensusTxPosition = "5:2:0".parse().unwrap();
+        assert_eq!(
+            position.authority_name(&committee).unwrap(),
+            *committee.authority_by_index(2).unwrap()
+        );
+
+        let position: ConsensusTxPosition =
+            format!("5:{}:0", committee.num_members()).parse().unwrap();
+        assert!(position.authority_name(&committee).is_err());
+    }
+
+    #[test]
+    fn test_position_block_ref_conversions() {
+        let position = ConsensusTxPosition::new(BlockRef::MAX, 7);
+        assert_eq!(BlockRef::from(position), BlockRef::MAX);
+
+        let position: ConsensusTxPosition = BlockRef::MAX.into();
+        assert_eq!(position, ConsensusTxPosition::new(BlockRef::MAX, 0));
+    }
+
+    #[test]
+    fn test_position_ordering() {
+        let digest_a = BlockRef::MIN.digest;
+        let digest_b = BlockRef::MAX.digest;
+        let position = |round, author, index, digest| {
+            ConsensusTxPosition::new(
+                BlockRef {
+                    round,
+                    author: consensus_config::AuthorityIndex::new_for_test(author),
+                    digest,
+                },
+                index,
+            )
+        };
+
+        // Round is compared first, then author, then transaction index.
+        assert!(position(1, 3, 5, digest_b) < position(2, 0, 0, digest_a));
+        assert!(position(1, 0, 5, digest_b) < position(1, 1, 0, digest_a));
+        assert!(position(1, 1, 0, digest_b) < position(1, 1, 1, digest_a));
+        // The digest only breaks ties between otherwise equal positions.
+        assert!(position(1, 1, 1, digest_a) < position(1, 1, 1, digest_b));
+        assert_eq!(
+            position(1, 1, 1, digest_a).cmp(&position(1, 1, 1, digest_a)),
+            std::cmp::Ordering::Equal
+        );
+
+        let mut positions = vec![
+            position(2, 0, 0, digest_a),
+            position(1, 1, 0, digest_a),
+            position(1, 0, 1, digest_a),
+            position(1, 0, 0, digest_a),
+        ];
+        positions.sort();
+        assert_eq!(
+            positions,
+            vec![
+                position(1, 0, 0, digest_a),
+                position(1, 0, 1, digest_a),
+                position(1, 1, 0, digest_a),
+                position(2, 0, 0, digest_a),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_request_builder() {
+        let digest = TransactionDigest::random();
+
+        let request = WaitForEffectsRequestBuilder::new(1, digest, Some(test_position())).build();
+        assert_eq!(request.epoch, 1);
+        assert_eq!(request.transaction_digest, digest);
+        assert_eq!(request.transaction_position, Some(test_position()));
+        assert!(!request.include_details);
+
+        let request = WaitForEffectsRequestBuilder::new(1, digest, Some(test_position()))
+            .with_details()
+            .build();
+        assert!(request.include_details);
+    }
+
+    #[test]
+    fn test_request_display() {
+        let digest = TransactionDigest::random();
+        let request = WaitForEffectsRequestBuilder::new(5, digest, Some(test_position()))
+            .with_details()
+            .build();
+        assert_eq!(
+            request.to_string(),
+            format!(
+                "WaitForEffects(digest={}, epoch=5, position={}:3, details=true)",
+                digest,
+                BlockRef::MIN
+            )
+        );
+
+        // Debug output includes the consensus position fields.
+        let debug = format!("{:?}", request);
+        assert!(debug.contains("transaction_position"));
+        assert!(debug.contains("index: 3"));
+    }
+
+    #[test]
+    fn test_request_without_position() {
+        let digest = TransactionDigest::random();
+        let request = WaitForEffectsRequestBuilder::new(3, digest, None).build();
+        assert_eq!(
+            request.to_string(),
+            format!(
+                "WaitForEffects(digest={}, epoch=3, position=none, details=false)",
+                digest
+            )
+        );
+
+        let raw = RawWaitForEffectsRequest::try_from(request).unwrap();
+        assert!(raw.transaction_position.is_none());
+        let request = WaitForEffectsRequest::try_from(raw).unwrap();
+        assert_eq!(request.transaction_digest, digest);
+        assert_eq!(request.transaction_position, None);
+    }
+
+    #[test]
+    fn test_request_validate() {
+        let request = WaitForEffectsRequestBuilder::new(
+            1,
+            TransactionDigest::random(),
+            Some(test_position()),
+        )
+        .build();
+        assert!(request.validate().is_ok());
+
+        let request =
+            WaitForEffectsRequestBuilder::new(1, TransactionDigest::ZERO, Some(test_position()))
+                .build();
+        assert!(matches!(
+            request.validate(),
+            Err(SuiError::GrpcMessageDeserializeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_request_default() {
+        let request = WaitForEffectsRequest::default();
+        assert_eq!(request.epoch, 0);
+        assert_eq!(request.transaction_digest, TransactionDigest::ZERO);
+        assert_eq!(
+            request.transaction_position,
+            Some(ConsensusTxPosition::new(BlockRef::MIN, 0))
+        );
+        assert!(!request.include_details);
+        assert!(!request.include_auxiliary_data);
+        assert!(request.validate().is_err());
+
+        let request = WaitForEffectsRequest {
+            transaction_digest: TransactionDigest::random(),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_request_auxiliary_data() {
+        let digest = TransactionDigest::random();
+        let request = WaitForEffectsRequestBuilder::new(1, digest, Some(test_position()))
+            .with_auxiliary_data()
+            .build();
+        assert!(request.include_details);
+        assert!(request.include_auxiliary_data);
+        assert!(request.validate().is_ok());
+
+        let raw = RawWaitForEffectsRequest::try_from(request).unwrap();
+        assert!(raw.include_auxiliary_data);
+        let request = WaitForEffectsRequest::try_from(raw).unwrap();
+        assert!(request.include_auxiliary_data);
+
+        // Auxiliary data cannot be requested without details.
+        let request = WaitForEffectsRequest {
+            include_details: false,
+            ..request
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn test_gas_summary_roundtrip() {
+        let digest = TransactionDigest::random();
+        let request = WaitForEffectsRequestBuilder::new(1, digest, Some(test_position()))
+            .with_gas_summary()
+            .build();
+        // The gas cost summary does not require details.
+        assert!(!request.include_details);
+        assert!(request.validate().is_ok());
+        let raw = RawWaitForEffectsRequest::try_from(request.clone()).unwrap();
+        assert!(raw.include_gas_summary);
+        assert_eq!(WaitForEffectsRequest::try_from(raw).unwrap(), request);
+
+        let response = WaitForEffectsResponse::Executed {
+            effects_digest: TransactionEffectsDigest::random(),
+            details: None,
+            gas_cost_summary: Some(GasCostSummary::new(1000, 2000, 500, 20)),
+        };
+        let raw = RawWaitForEffectsResponse::try_from(response.clone()).unwrap();
+        assert_eq!(WaitForEffectsResponse::try_from(raw).unwrap(), response);
+    }
+
+    #[test]
+    fn test_request_json_roundtrip() {
+        let digest = TransactionDigest::random();
+        let request = WaitForEffectsRequestBuilder::new(7, digest, Some(test_position()))
+            .with_details()
+            .build();
+        let json = request.to_json().unwrap();
+        let decoded = WaitForEffectsRequest::from_json(&json).unwrap();
+        assert_eq!(decoded.epoch, 7);
+        assert_eq!(decoded.transaction_digest, digest);
+        assert_eq!(decoded.transaction_position, Some(test_position()));
+        assert!(decoded.include_details);
+
+        assert!(WaitForEffectsRequest::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_response_json_roundtrip() {
+        let effects_digest = TransactionEffectsDigest::random();
+        let response = WaitForEffectsResponse::Executed {
+            effects_digest,
+            details: None,
+            gas_cost_summary: None,
+        };
+        let json = response.to_json().unwrap();
+        let WaitForEffectsResponse::Executed {
+            effects_digest: decoded_digest,
+            details,
+            ..
+        } = WaitForEffectsResponse::from_json(&json).unwrap()
+        else {
+            panic!("Expected executed response");
+        };
+        assert_eq!(decoded_digest, effects_digest);
+        assert!(details.is_none());
+
+        let response = WaitForEffectsResponse::Rejected {
+            reason: RejectReason::LockConflict("conflict".to_string()),
+            consensus_code: Some(ConsensusRejectionCode::FastPath),
+        };
+        let json = response.to_json().unwrap();
+        let WaitForEffectsResponse::Rejected {
+            reason,
+            consensus_code,
+        } = WaitForEffectsResponse::from_json(&json).unwrap()
+        else {
+            panic!("Expected rejected response");
+        };
+        assert_eq!(reason, RejectReason::LockConflict("conflict".to_string()));
+        assert_eq!(consensus_code, Some(ConsensusRejectionCode::FastPath));
+
+        let json = WaitForEffectsResponse::Expired(42).to_json().unwrap();
+        assert!(matches!(
+            WaitForEffectsResponse::from_json(&json).unwrap(),
+            WaitForEffectsResponse::Expired(42)
+        ));
+    }
+
+    #[test]
+    fn test_request_roundtrip() {
+        let digest = TransactionDigest::random();
+        let built = WaitForEffectsRequestBuilder::new(2, digest, Some(test_position()))
+            .with_details()
+            .build();
+        let constructed = WaitForEffectsRequest {
+            epoch: 2,
+            transaction_digest: digest,
+            transaction_position: Some(test_position()),
+            include_details: true,
+            include_auxiliary_data: false,
+            include_gas_summary: false,
+        };
+
+        // Both construction paths produce the same request on the wire.
+        let raw_built = RawWaitForEffectsRequest::try_from(built).unwrap();
+        let raw_constructed = RawWaitForEffectsRequest::try_from(constructed).unwrap();
+        assert_eq!(raw_built.epoch, raw_constructed.epoch);
+        assert_eq!(
+            raw_built.transaction_digest,
+            raw_constructed.transaction_digest
+        );
+        assert_eq!(
+            raw_built.transaction_position,
+            raw_constructed.transaction_position
+        );
+        assert_eq!(raw_built.include_details, raw_constructed.include_details);
+
+        let request = WaitForEffectsRequest::try_from(raw_built).unwrap();
+        assert_eq!(request.epoch, 2);
+        assert_eq!(request.transaction_digest, digest);
+        assert_eq!(request.transaction_position, Some(test_position()));
+        assert!(request.include_details);
+    }
+
+    #[test]
+    fn test_rejected_response_roundtrip() {
+        for consensus_code in [
+            None,
+            Some(ConsensusRejectionCode::FastPath),
+            Some(ConsensusRejectionCode::PostCommit),
+        ] {
+            let response = WaitForEffectsResponse::Rejected {
+                reason: RejectReason::Overload("busy".to_string()),
+                consensus_code,
+            };
+            let raw = RawWaitForEffectsResponse::try_from(response).unwrap();
+            let WaitForEffectsResponse::Rejected {
+                reason,
+                consensus_code: decoded_code,
+            } = WaitForEffectsResponse::try_from(raw).unwrap()
+            else {
+                panic!("Expected rejected response");
+            };
+            assert_eq!(reason, RejectReason::Overload("busy".to_string()));
+            assert_eq!(decoded_code, consensus_code);
+        }
+
+        // Unknown codes are decoded as unset, keeping the rest of the response.
+        let raw = RawWaitForEffectsResponse {
+            inner: Some(RawValidatorTransactionStatus::Rejected(RawRejectedStatus {
+                reason: RawRejectReason::CoinDenyList as i32,
+                message: None,
+                consensus_code: Some(100),
+            })),
+        };
+        assert_eq!(
+            WaitForEffectsResponse::try_from(raw).unwrap(),
+            WaitForEffectsResponse::Rejected {
+                reason: RejectReason::CoinDenyList,
+                consensus_code: None,
+            }
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_effects() {
+        let notify = Arc::new(NotifyRead::new());
+        let digest = TransactionDigest::random();
+
+        // The response is decoded once it is published for the digest.
+        let notify_clone = notify.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            let raw =
+                RawWaitForEffectsResponse::try_from(WaitForEffectsResponse::Expired(5)).unwrap();
+            notify_clone.notify(&TransactionDigest::random(), &raw);
+            notify_clone.notify(&digest, &raw);
+        });
+        let response = wait_for_effects(&digest, Duration::from_secs(10), notify.clone())
+            .await
+            .unwrap();
+        assert!(matches!(response, WaitForEffectsResponse::Expired(5)));
+
+        // Malformed responses fail to decode.
+        let notify_clone = notify.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            notify_clone.notify(&digest, &RawWaitForEffectsResponse { inner: None });
+        });
+        let err = wait_for_effects(&digest, Duration::from_secs(10), notify.clone())
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, SuiError::GrpcMessageDeserializeError { .. }));
+
+        // Without a response, the wait times out.
+        let err = wait_for_effects(&digest, Duration::from_secs(10), notify.clone())
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, SuiError::TimeoutError));
+        assert_eq!(notify.num_pending(), 0);
+    }
+
+    #[test]
+    fn test_response_too_many_objects() {
+        let effects_digest = bcs::to_bytes(&TransactionEffectsDigest::random()).unwrap();
+        let make_response = |num_objects: usize| RawWaitForEffectsResponse {
+            inner: Some(RawValidatorTransactionStatus::Executed(RawExecutedStatus {
+                effects_digest: effects_digest.clone().into(),
+                details: Some(RawExecutedData {
+                    effects: Default::default(),
+                    events: None,
+                    input_objects: vec![Default::default(); num_objects],
+                    output_objects: vec![],
+                    auxiliary_objects: vec![],
+                    signature: None,
+                }),
+                gas_cost_summary: None,
+            })),
+        };
+
+        // Within the limit, the response fails later on the invalid effects bytes.
+        let err = WaitForEffectsResponse::try_from(make_response(MAX_OBJECTS_PER_RESPONSE))
+            .err()
+            .unwrap();
+        let SuiError::GrpcMessageDeserializeError { type_info, .. } = err else {
+            panic!("Unexpected error: {:?}", err);
+        };
+        assert_eq!(type_info, "RawWaitForEffectsResponse.details.effects");
+
+        let err = WaitForEffectsResponse::try_from(make_response(MAX_OBJECTS_PER_RESPONSE + 1))
+            .err()
+            .unwrap();
+        let SuiError::GrpcMessageDeserializeError { type_info, .. } = err else {
+            panic!("Unexpected error: {:?}", err);
+        };
+        assert_eq!(type_info, "RawWaitForEffectsResponse.input_objects");
+    }
+
+    #[test]
+    fn test_response_auxiliary_objects_roundtrip() {
+        let effects = TransactionEffects::default();
+        let auxiliary_object = Object::new_gas_for_testing();
+        let response = WaitForEffectsResponse::Executed {
+            effects_digest: effects.digest(),
+            details: Some(Box::new(ExecutedData {
+                effects,
+                events: None,
+                input_objects: vec![],
+                output_objects: vec![],
+                auxiliary_objects: vec![auxiliary_object.clone()],
+                signature: None,
+            })),
+            gas_cost_summary: None,
+        };
+        let raw = RawWaitForEffectsResponse::try_from(response).unwrap();
+        let WaitForEffectsResponse::Executed {
+            details: Some(details),
+            ..
+        } = WaitForEffectsResponse::try_from(raw).unwrap()
+        else {
+            panic!("Expected executed response with details");
+        };
+        assert!(details.output_objects.is_empty());
+        assert_eq!(
+            details
+                .auxiliary_objects
+                .iter()
+                .map(Object::compute_object_reference)
+                .collect::<Vec<_>>(),
+            vec![auxiliary_object.compute_object_reference()]
+        );
+    }
+
+    #[test]
+    fn test_json_codec_roundtrip() {
+        let request = WaitForEffectsRequest {
+            transaction_digest: TransactionDigest::random(),
+            transaction_position: Some(test_position()),
+            include_details: true,
+            ..Default::default()
+        };
+        let raw = request.clone().into_raw::<JsonCodec>().unwrap();
+        assert_eq!(
+            WaitForEffectsRequest::from_raw::<JsonCodec>(raw.clone()).unwrap(),
+            request
+        );
+        // Fields encoded with one codec cannot be decoded with another.
+        assert!(WaitForEffectsRequest::try_from(raw).is_err());
+
+        let response = WaitForEffectsResponse::Executed {
+            effects_digest: TransactionEffectsDigest::random(),
+            details: Some(Box::new(ExecutedData {
+                effects: TransactionEffects::default(),
+                events: Some(TransactionEvents::default()),
+                input_objects: vec![Object::new_gas_for_testing()],
+                output_objects: vec![],
+                auxiliary_objects: vec![],
+                signature: None,
+            })),
+            gas_cost_summary: None,
+        };
+        let raw = response.clone().into_raw::<JsonCodec>().unwrap();
+        let decoded = WaitForEffectsResponse::from_raw::<JsonCodec>(raw).unwrap();
+        assert_eq!(
+            bcs::to_bytes(&decoded).unwrap(),
+            bcs::to_bytes(&response).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_clone() {
+        let request = WaitForEffectsRequest {
+            transaction_digest: TransactionDigest::random(),
+            include_details: true,
+            ..Default::default()
+        };
+        assert_eq!(request.clone(), request);
+
+        let response = WaitForEffectsResponse::Executed {
+            effects_digest: TransactionEffectsDigest::random(),
+            details: Some(Box::new(ExecutedData {
+                effects: TransactionEffects::default(),
+                events: Some(TransactionEvents {
+                    data: vec![Event::random_for_testing()],
+                }),
+                input_objects: vec![Object::new_gas_for_testing()],
+                output_objects: vec![Object::new_gas_for_testing()],
+                auxiliary_objects: vec![Object::new_gas_for_testing()],
+                signature: None,
+            })),
+            gas_cost_summary: None,
+        };
+        assert_eq!(response.clone(), response);
+    }
+
+    #[test]
+    fn test_response_eq() {
+        let effects_digest = TransactionEffectsDigest::random();
+        let executed = WaitForEffectsResponse::Executed {
+            effects_digest,
+            details: None,
+            gas_cost_summary: None,
+        };
+        assert_eq!(
+            executed,
+            WaitForEffectsResponse::Executed {
+                effects_digest,
+                details: None,
+                gas_cost_summary: None,
+            }
+        );
+        assert_ne!(
+            executed,
+            WaitForEffectsResponse::Executed {
+                effects_digest,
+                details: Some(Box::new(ExecutedData {
+                    effects: TransactionEffects::default(),
+                    events: None,
+                    input_objects: vec![],
+                    output_objects: vec![],
+                    auxiliary_objects: vec![],
+                    signature: None,
+                })),
+                gas_cost_summary: None,
+            }
+        );
+
+        let rejected = WaitForEffectsResponse::Rejected {
+            reason: RejectReason::None,
+            consensus_code: Some(ConsensusRejectionCode::PostCommit),
+        };
+        let raw = RawWaitForEffectsResponse::try_from(rejected.clone()).unwrap();
+        assert_eq!(WaitForEffectsResponse::try_from(raw).unwrap(), rejected);
+        assert_ne!(rejected, WaitForEffectsResponse::Expired(1));
+    }
+
+    #[test]
+    fn test_response_contents() {
+        for (has_events, has_input_objects, has_output_objects) in
+            itertools::iproduct!([false, true], [false, true], [false, true])
+        {
+            let response = WaitForEffectsResponse::Executed {
+                effects_digest: TransactionEffectsDigest::random(),
+                details: Some(Box::new(ExecutedData {
+                    effects: TransactionEffects::default(),
+                    events: has_events.then(|| TransactionEvents {
+                        data: vec![Event::random_for_testing()],
+                    }),
+                    input_objects: has_input_objects
+                        .then(Object::new_gas_for_testing)
+                        .into_iter()
+                        .collect(),
+                    output_objects: has_output_objects
+                        .then(Object::new_gas_for_testing)
+                        .into_iter()
+                        .collect(),
+                    auxiliary_objects: vec![],
+                    signature: None,
+                })),
+                gas_cost_summary: None,
+            };
+            assert_eq!(response.has_events(), has_events);
+            assert_eq!(response.has_input_objects(), has_input_objects);
+            assert_eq!(response.has_output_objects(), has_output_objects);
+            assert_eq!(
+                response.is_minimal(),
+                !has_events && !has_input_objects && !has_output_objects
+            );
+        }
+
+        // Empty events and auxiliary objects.
+        let response = |events, auxiliary_objects| WaitForEffectsResponse::Executed {
+            effects_digest: TransactionEffectsDigest::random(),
+            details: Some(Box::new(ExecutedData {
+                effects: TransactionEffects::default(),
+                events,
+                input_objects: vec![],
+                output_objects: vec![],
+                auxiliary_objects,
+                signature: None,
+            })),
+            gas_cost_summary: None,
+        };
+        let empty_events = response(Some(TransactionEvents::default()), vec![]);
+        assert!(!empty_events.has_events());
+        assert!(empty_events.is_minimal());
+        assert!(!response(None, vec![Object::new_gas_for_testing()]).is_minimal());
+
+        // Responses without details.
+        let no_details = WaitForEffectsResponse::Executed {
+            effects_digest: TransactionEffectsDigest::random(),
+            details: None,
+            gas_cost_summary: None,
+        };
+        assert!(!no_details.has_events());
+        assert!(!no_details.has_input_objects());
+        assert!(!no_details.has_output_objects());
+        assert!(no_details.is_minimal());
+        let rejected = WaitForEffectsResponse::Rejected {
+            reason: RejectReason::None,
+            consensus_code: None,
+        };
+        assert!(!rejected.has_events());
+        assert!(!rejected.is_minimal());
+        assert!(!WaitForEffectsResponse::Expired(1).is_minimal());
+    }
+
+    #[test]
+    fn test_response_summary() {
+        let effects = TransactionEffects::default();
+        let effects_digest = effects.digest();
+        let input_object = Object::new_gas_for_testing();
+        let output_object = Object::new_gas_for_testing();
+        let auxiliary_object = Object::new_gas_for_testing();
+        let response = WaitForEffectsResponse::Executed {
+            effects_digest,
+            details: Some(Box::new(ExecutedData {
+                effects: effects.clone(),
+                events: None,
+                input_objects: vec![input_object.clone()],
+                output_objects: vec![output_object.clone()],
+                auxiliary_objects: vec![auxiliary_object.clone()],
+                signature: None,
+            })),
+            gas_cost_summary: None,
+        };
+
+        let summary = WaitForEffectsSummary::from(response);
+        assert_eq!(
+            summary,
+            WaitForEffectsSummary::Executed {
+                effects_digest,
+                details: Some(ExecutedDataSummary {
+                    effects: EffectsSummary {
+                        transaction_digest: *effects.transaction_digest(),
+                        executed_epoch: effects.executed_epoch(),
+                        status: effects.status().clone(),
+                        gas_cost_summary: effects.gas_cost_summary().clone(),
+                    },
+                    num_events: 0,
+                    input_objects: vec![input_object.compute_object_reference()],
+                    output_objects: vec![output_object.compute_object_reference()],
+                    auxiliary_objects: vec![auxiliary_object.compute_object_reference()],
+                }),
+            }
+        );
+
+        assert_eq!(
+            WaitForEffectsSummary::from(WaitForEffectsResponse::Expired(7)),
+            WaitForEffectsSummary::Expired(7)
+        );
+    }
+
+    mod proptests {
+        use super::*;
+        use proptest::collection;
+        use proptest::prelude::*;
+
+        proptest! {
+            // Positions are built from their BCS fields: (round, author, digest, index).
+            #[test]
+            fn test_position_bcs_roundtrip(
+                round in any::<u32>(),
+                author in any::<u32>(),
+                digest in any::<[u8; 32]>(),
+                index in any::<TransactionIndex>(),
+            ) {
+                let bytes = bcs::to_bytes(&(round, author, digest, index)).unwrap();
+                let position: ConsensusTxPosition = bcs::from_bytes(&bytes).unwrap();
+                prop_assert_eq!(position.block.round, round);
+                prop_assert_eq!(position.index, index);
+                prop_assert_eq!(bcs::to_bytes(&position).unwrap(), bytes);
+                let decoded: ConsensusTxPosition =
+                    bcs::from_bytes(&bcs::to_bytes(&position).unwrap()).unwrap();
+                prop_assert_eq!(decoded, position);
+            }
+
+            // Check that converting malformed requests does not panic.
+            #[test]
+            fn test_raw_request_conversion(
+                epoch in any::<u64>(),
+                transaction_digest in collection::vec(any::<u8>(), 0..64),
+                transaction_position in proptest::option::of(collection::vec(any::<u8>(), 0..64)),
+                include_details in any::<bool>(),
+                include_auxiliary_data in any::<bool>(),
+            ) {
+                let raw = RawWaitForEffectsRequest {
+                    epoch,
+                    transaction_digest: transaction_digest.into(),
+                    transaction_position: transaction_position.map(Into::into),
+                    include_details,
+                    include_auxiliary_data,
+                    include_gas_summary: false,
+                };
+                if let Ok(request) = WaitForEffectsRequest::try_from(raw) {
+                    let _ = request.validate();
+                }
+            }
+        }
+    }
+
+    fn raw_response_of_size(payload_size: usize) -> RawWaitForEffectsResponse {
+        RawWaitForEffectsResponse {
+            inner: Some(RawValidatorTransactionStatus::Executed(RawExecutedStatus {
+                effects_digest: Bytes::from(vec![0u8; payload_size]),
+                details: None,
+                gas_cost_summary: None,
+            })),
+        }
+    }
+
+    fn cache_key(include_details: bool) -> WaitForEffectsResponseCacheKey {
+        WaitForEffectsResponseCacheKey {
+            epoch: 0,
+            transaction_digest: TransactionDigest::random(),
+            include_details,
+            include_auxiliary_data: false,
+            include_gas_summary: false,
+        }
+    }
+
+    #[test]
+    fn test_response_cache_evicts_by_size() {
+        let response_size = raw_response_of_size(100).encoded_len();
+        let cache = WaitForEffectsResponseCache::new(response_size * 3);
+        assert!(cache.is_empty());
+
+        let keys: Vec<_> = (0..3).map(|_| cache_key(true)).collect();
+        for key in &keys {
+            cache.insert(*key, raw_response_of_size(100));
+        }
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.size_bytes(), response_size * 3);
+
+        // Reading the first response makes the second one the least recently used.
+        let cached = cache.get(&keys[0]).unwrap();
+        assert_eq!(cached.encoded_len(), response_size);
+
+        let new_key = cache_key(true);
+        cache.insert(new_key, raw_response_of_size(100));
+        assert_eq!(cache.len(), 3);
+        assert!(cache.get(&keys[1]).is_none());
+        assert!(cache.get(&keys[0]).is_some());
+        assert!(cache.get(&keys[2]).is_some());
+        assert!(cache.get(&new_key).is_some());
+
+        // A large response evicts as many responses as needed to fit.
+        let large_key = cache_key(false);
+        cache.insert(large_key, raw_response_of_size(150));
+        assert!(cache.get(&large_key).is_some());
+        assert!(cache.size_bytes() <= response_size * 3);
+        assert_eq!(cache.len(), 2);
+
+        // A response larger than the whole cache is not cached.
+        let huge_key = cache_key(false);
+        cache.insert(huge_key, raw_response_of_size(1000));
+        assert!(cache.get(&huge_key).is_none());
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_response_cache_replaces_response() {
+        let cache = WaitForEffectsResponseCache::new(WAIT_FOR_EFFECTS_RESPONSE_CACHE_SIZE_BYTES);
+        let key = cache_key(true);
+        cache.insert(key, raw_response_of_size(100));
+        cache.insert(key, raw_response_of_size(10));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.size_bytes(), raw_response_of_size(10).encoded_len());
+
+        // Requests with different details are cached separately.
+        let request = WaitForEffectsRequest {
+            transaction_digest: key.transaction_digest,
+            include_details: false,
+            ..Default::default()
+        };
+        assert!(cache.get(&(&request).into()).is_none());
+        let request = WaitForEffectsRequest {
+            include_details: true,
+            ..request
+        };
+        assert!(cache.get(&(&request).into()).is_some());
+    }
+
+    async fn collect_chunks(
+        stream: impl Stream<Item = SuiResult<RawWaitForEffectsResponseChunk>>,
+    ) -> Vec<RawWaitForEffectsResponseChunk> {
+        stream.map(|chunk| chunk.unwrap()).collect().await
+    }
+
+    #[tokio::test]
+    async fn test_response_chunks_roundtrip() {
+        let objects: Vec<_> = (0..5).map(|_| Object::new_gas_for_testing()).collect();
+        let response = WaitForEffectsResponse::Executed {
+            effects_digest: TransactionEffectsDigest::random(),
+            details: Some(Box::new(ExecutedData {
+                effects: TransactionEffects::default(),
+                events: Some(TransactionEvents {
+                    data: vec![Event::random_for_testing()],
+                }),
+                input_objects: objects[..2].to_vec(),
+                output_objects: objects.clone(),
+                auxiliary_objects: vec![],
+                signature: None,
+            })),
+            gas_cost_summary: None,
+        };
+        let chunks = collect_chunks(response_chunk_stream(response.clone(), 2).unwrap()).await;
+        // One effects chunk, one chunk of input objects and three chunks of output objects.
+        assert_eq!(chunks.len(), 5);
+        let Some(RawWaitForEffectsChunk::EffectsChunk(first)) = &chunks[0].inner else {
+            panic!("Expected effects chunk first");
+        };
+        let Some(RawValidatorTransactionStatus::Executed(RawExecutedStatus {
+            details: Some(details),
+            ..
+        })) = &first.inner
+        else {
+            panic!("Expected executed response with details");
+        };
+        assert!(details.events.is_some());
+        assert!(details.input_objects.is_empty());
+        assert!(details.output_objects.is_empty());
+
+        let assembled = assemble_response_from_chunks(chunks).unwrap();
+        assert_eq!(
+            WaitForEffectsResponse::try_from(assembled).unwrap(),
+            response
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_chunks_without_objects() {
+        for response in [
+            WaitForEffectsResponse::Executed {
+                effects_digest: TransactionEffectsDigest::random(),
+                details: None,
+                gas_cost_summary: None,
+            },
+            WaitForEffectsResponse::Expired(7),
+        ] {
+            let chunks = collect_chunks(
+                response_chunk_stream(response.clone(), OBJECTS_PER_RESPONSE_CHUNK).unwrap(),
+            )
+            .await;
+            assert_eq!(chunks.len(), 1);
+            let assembled = assemble_response_from_chunks(chunks).unwrap();
+            assert_eq!(
+                WaitForEffectsResponse::try_from(assembled).unwrap(),
+                response
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assemble_invalid_chunks() {
+        let object_chunk = RawWaitForEffectsResponseChunk {
+            inner: Some(RawWaitForEffectsChunk::ObjectChunk(
+                RawObjectChunk::default(),
+            )),
+        };
+        assert!(assemble_response_from_chunks(vec![]).is_err());
+        assert!(assemble_response_from_chunks(vec![object_chunk.clone()]).is_err());
+
+        // Objects cannot be added to a response without details.
+        let mut chunks = collect_chunks(
+            response_chunk_stream(
+                WaitForEffectsResponse::Expired(7),
+                OBJECTS_PER_RESPONSE_CHUNK,
+            )
+            .unwrap(),
+        )
+        .await;
+        chunks.push(object_chunk);
+        assert!(assemble_response_from_chunks(chunks.clone()).is_err());
+
+        // Only the first chunk can carry the effects.
+        let effects_chunk = chunks[0].clone();
+        assert!(assemble_response_from_chunks(vec![effects_chunk.clone(), effects_chunk]).is_err());
+    }
+
+    #[test]
+    fn test_verify_response_signature() {
+        let (committee, key_pairs) = Committee::new_simple_test_committee();
+        let (_, foreign_key_pair): (_, AuthorityKeyPair) = get_key_pair();
+        let effects = TransactionEffects::default();
+        let sign = |key_pair: &AuthorityKeyPair| {
+            AuthoritySignInfo::new(
+                committee.epoch(),
+                &effects,
+                Intent::sui_app(IntentScope::TransactionEffects),
+                key_pair.public().into(),
+                key_pair,
+            )
+        };
+        let response = |effects_digest, signature| WaitForEffectsResponse::Executed {
+            effects_digest,
+            details: Some(Box::new(ExecutedData {
+                effects: effects.clone(),
+                events: None,
+                input_objects: vec![],
+                output_objects: vec![],
+                auxiliary_objects: vec![],
+                signature,
+            })),
+            gas_cost_summary: None,
+        };
+
+        let signed = response(effects.digest(), Some(sign(&key_pairs[0])));
+        signed.verify(&committee).unwrap();
+        // The signature survives the raw conversion.
+        let raw = RawWaitForEffectsResponse::try_from(signed.clone()).unwrap();
+        WaitForEffectsResponse::try_from(raw)
+            .unwrap()
+            .verify(&committee)
+            .unwrap();
+
+        // The signer must be in the committee.
+        let foreign = response(effects.digest(), Some(sign(&foreign_key_pair)));
+        assert!(foreign.verify(&committee).is_err());
+
+        // The effects must match the digest of the response.
+        let tampered = response(
+            TransactionEffectsDigest::random(),
+            Some(sign(&key_pairs[0])),
+        );
+        assert!(tampered.verify(&committee).is_err());
+
+        // Unsigned executed responses cannot be verified.
+        assert!(response(effects.digest(), None).verify(&committee).is_err());
+        assert!(WaitForEffectsResponse::Executed {
+            effects_digest: effects.digest(),
+            details: None,
+            gas_cost_summary: None,
+        }
+        .verify(&committee)
+        .is_err());
+
+        // Expired responses are not signed, so they cannot be authenticated.
+        assert!(WaitForEffectsResponse::Expired(1)
+            .verify(&committee)
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify_response_digest() {
+        let effects = TransactionEffects::default();
+        let response = |effects_digest| WaitForEffectsResponse::Executed {
+            effects_digest,
+            details: Some(Box::new(ExecutedData {
+                effects: effects.clone(),
+                events: None,
+                input_objects: vec![],
+                output_objects: vec![],
+                auxiliary_objects: vec![],
+                signature: None,
+            })),
+            gas_cost_summary: None,
+        };
+
+        // No signature is needed to check the digest.
+        response(effects.digest()).verify_digest().unwrap();
+        let raw = RawWaitForEffectsResponse::try_from(response(effects.digest())).unwrap();
+        WaitForEffectsResponse::try_from(raw)
+            .unwrap()
+            .verify_digest()
+            .unwrap();
+
+        assert!(response(TransactionEffectsDigest::random())
+            .verify_digest()
+            .is_err());
+
+        // Responses without effects have nothing to check.
+        WaitForEffectsResponse::Executed {
+            effects_digest: TransactionEffectsDigest::random(),
+            details: None,
+            gas_cost_summary: None,
+        }
+        .verify_digest()
+        .unwrap();
+        WaitForEffectsResponse::Expired(1).verify_digest().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_shares_response() {
+        let dispatcher = WaitForEffectsDispatcher::new();
+        let request = WaitForEffectsRequest {
+            transaction_digest: TransactionDigest::random(),
+            ..Default::default()
+        };
+        let response = RawWaitForEffectsResponse::try_from(WaitForEffectsResponse::Executed {
+            effects_digest: TransactionEffectsDigest::random(),
+            details: None,
+            gas_cost_summary: None,
+        })
+        .unwrap();
+        let num_waits = std::sync::atomic::AtomicUsize::new(0);
+        let executed = tokio::sync::Notify::new();
+
+        let callers = (0..10).map(|_| {
+            dispatcher.dispatch(&request, || async {
+                num_waits.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                executed.notified().await;
+                Ok(response.clone())
+            })
+        });
+        let results = futures::future::join(futures::future::join_all(callers), async {
+            // Let all callers register before the response becomes available.
+            tokio::task::yield_now().await;
+            assert_eq!(dispatcher.num_in_flight(), 1);
+            executed.notify_one();
+        })
+        .await
+        .0;
+
+        assert_eq!(num_waits.load(std::sync::atomic::Ordering::Relaxed), 1);
+        for result in results {
+            assert_eq!(
+                WaitForEffectsResponse::try_from(result.unwrap()).unwrap(),
+                WaitForEffectsResponse::try_from(response.clone()).unwrap()
+            );
+        }
+        assert_eq!(dispatcher.num_in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_cancelled_caller() {
+        let dispatcher = WaitForEffectsDispatcher::new();
+        let request = WaitForEffectsRequest {
+            transaction_digest: TransactionDigest::random(),
+            ..Default::default()
+        };
+
+        // The first caller never finishes and is cancelled.
+        let mut first =
+            Box::pin(dispatcher.dispatch(&request, || futures::future::pending::<SuiResult<_>>()));
+        assert!(futures::poll!(first.as_mut()).is_pending());
+        let mut second =
+            Box::pin(dispatcher.dispatch(&request, || async { Err(SuiError::TimeoutError) }));
+        assert!(futures::poll!(second.as_mut()).is_pending());
+        drop(first);
+
+        // The second caller takes over and computes the response itself.
+        assert!(matches!(second.await, Err(SuiError::TimeoutError)));
+        assert_eq!(dispatcher.num_in_flight(), 0);
+    }
+}
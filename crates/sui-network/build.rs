@@ -42,6 +42,16 @@ fn main() -> Result<()> {
                 .codec_path(prost_codec_path)
                 .build(),
         )
+        .method(
+            Method::builder()
+                .name("wait_for_effects_stream")
+                .route_name("WaitForEffectsStream")
+                .input_type("sui_types::messages_grpc::RawWaitForEffectsRequest")
+                .output_type("sui_types::messages_grpc::RawWaitForEffectsResponseChunk")
+                .codec_path(prost_codec_path)
+                .server_streaming()
+                .build(),
+        )
         .method(
             Method::builder()
                 .name("transaction")
@@ -73,6 +73,10 @@ use tracing::info;
 // Get the estimated cost of a transaction
 //
 //  $ curl 'http://127.0.0.1:1337/get-tx-cost?tx=<tx_digest>'
+//
+// Dump the consensus positions of transactions currently known to be rejected by consensus
+//
+//  $ curl 'http://127.0.0.1:1337/dump-consensus-rejected-txs'
 
 const LOGGING_ROUTE: &str = "/logging";
 const TRACING_ROUTE: &str = "/enable-tracing";
@@ -87,6 +91,7 @@ const RANDOMNESS_INJECT_PARTIAL_SIGS_ROUTE: &str = "/randomness-inject-partial-s
 const RANDOMNESS_INJECT_FULL_SIG_ROUTE: &str = "/randomness-inject-full-sig";
 const GET_TX_COST_ROUTE: &str = "/get-tx-cost";
 const DUMP_CONSENSUS_TX_COST_ESTIMATES_ROUTE: &str = "/dump-consensus-tx-cost-estimates";
+const DUMP_CONSENSUS_REJECTED_TXS_ROUTE: &str = "/dump-consensus-rejected-txs";
 
 struct AppState {
     node: Arc<SuiNode>,
@@ -131,6 +136,10 @@ pub async fn run_admin_server(node: Arc<SuiNode>, port: u16, tracing_handle: Tra
             DUMP_CONSENSUS_TX_COST_ESTIMATES_ROUTE,
             get(dump_consensus_tx_cost_estimates),
         )
+        .route(
+            DUMP_CONSENSUS_REJECTED_TXS_ROUTE,
+            get(dump_consensus_rejected_txs),
+        )
         .with_state(Arc::new(app_state));
 
     let socket_address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port);
@@ -497,3 +506,9 @@ async fn dump_consensus_tx_cost_estimates(
     let estimates = epoch_store.get_consensus_tx_cost_estimates().await;
     (StatusCode::OK, format!("{:#?}", estimates))
 }
+
+async fn dump_consensus_rejected_txs(State(state): State<Arc<AppState>>) -> (StatusCode, String) {
+    let epoch_store = state.node.state().load_epoch_store_one_call_per_task();
+    let positions = epoch_store.get_consensus_rejected_tx_positions();
+    (StatusCode::OK, format!("{:#?}", positions))
+}
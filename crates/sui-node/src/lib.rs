@@ -568,6 +568,7 @@ impl SuiNode {
                 .get_highest_executed_checkpoint_seq_number()
                 .expect("checkpoint store read cannot fail")
                 .unwrap_or(0),
+            &config.consensus_tx_status_cache_config,
         )?;
 
         info!("created epoch store");
@@ -2008,6 +2009,12 @@ impl SuiNode {
         if let Some(validator_components) = &*self.validator_components.lock().await {
             validator_components.consensus_manager.shutdown().await;
         }
+        // Give transactions already being scheduled a chance to be sent for execution, then
+        // drop the ones still waiting for their input objects.
+        self.state
+            .shutdown_execution_scheduler(Duration::from_secs(5))
+            .await;
+        self.state.abort_execution_scheduler();
     }
 
     async fn reconfigure_state(
@@ -241,6 +241,7 @@ impl ValidatorConfigBuilder {
                 .unwrap_or(3600),
             zklogin_oauth_providers: default_zklogin_oauth_providers(),
             authority_overload_config: self.authority_overload_config.unwrap_or_default(),
+            consensus_tx_status_cache_config: Default::default(),
             execution_cache: self.execution_cache_config.unwrap_or_default(),
             run_with_range: None,
             jsonrpc_server_type: None,
@@ -547,6 +548,7 @@ impl FullnodeConfigBuilder {
             jwk_fetch_interval_seconds: 3600,
             zklogin_oauth_providers: default_zklogin_oauth_providers(),
             authority_overload_config: Default::default(),
+            consensus_tx_status_cache_config: Default::default(),
             run_with_range: self.run_with_range,
             jsonrpc_server_type: None,
             policy_config: self.policy_config,
@@ -788,6 +788,8 @@ impl From<crate::storage::error::Error> for SuiError {
     }
 }
 
+/// The BCS encoded error is attached as the status details, so that clients can inspect the
+/// error programmatically by converting the status back with `SuiError::from(status)`.
 impl From<SuiError> for Status {
     fn from(error: SuiError) -> Self {
         let bytes = bcs::to_bytes(&error).unwrap();
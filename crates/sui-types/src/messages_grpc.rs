@@ -404,6 +404,9 @@ impl SubmitTxResponse {
     }
 }
 
+// TODO: Responses with many objects are sent uncompressed. Consider enabling zstd compression
+// of the gRPC transport, which tonic already supports in this workspace, instead of adding a
+// compression field to these messages: it needs no change to the message format.
 #[derive(Clone, prost::Message)]
 pub struct RawWaitForEffectsRequest {
     #[prost(uint64, tag = "1")]
@@ -412,13 +415,23 @@ pub struct RawWaitForEffectsRequest {
     #[prost(bytes = "bytes", tag = "2")]
     pub transaction_digest: Bytes,
 
-    #[prost(bytes = "bytes", tag = "3")]
-    pub transaction_position: Bytes,
+    /// The position of the transaction in consensus.
+    /// Not set for transactions that are not sequenced through Mysticeti fastpath.
+    #[prost(bytes = "bytes", optional, tag = "3")]
+    pub transaction_position: Option<Bytes>,
 
     /// Whether to include details of the effects,
     /// including the effects content, events, input objects, and output objects.
     #[prost(bool, tag = "4")]
     pub include_details: bool,
+
+    /// Whether to include auxiliary objects, such as the gas object, in the details.
+    #[prost(bool, tag = "5")]
+    pub include_auxiliary_data: bool,
+
+    /// Whether to include the gas cost summary of the effects, with or without details.
+    #[prost(bool, tag = "6")]
+    pub include_gas_summary: bool,
 }
 
 #[derive(Clone, prost::Message)]
@@ -447,6 +460,8 @@ pub struct RawExecutedStatus {
     pub effects_digest: Bytes,
     #[prost(message, optional, tag = "2")]
     pub details: Option<RawExecutedData>,
+    #[prost(bytes = "bytes", optional, tag = "3")]
+    pub gas_cost_summary: Option<Bytes>,
 }
 
 #[derive(Clone, prost::Message)]
@@ -459,6 +474,36 @@ pub struct RawExecutedData {
     pub input_objects: Vec<Bytes>,
     #[prost(bytes = "bytes", repeated, tag = "4")]
     pub output_objects: Vec<Bytes>,
+    #[prost(bytes = "bytes", repeated, tag = "5")]
+    pub auxiliary_objects: Vec<Bytes>,
+    // Signature of the responding validator over the effects.
+    #[prost(bytes = "bytes", optional, tag = "6")]
+    pub signature: Option<Bytes>,
+}
+
+/// A chunk of a WaitForEffects response sent over a server stream.
+/// The first chunk carries the response without input and output objects, so that clients can
+/// process effects and events before the objects are received.
+#[derive(Clone, prost::Message)]
+pub struct RawWaitForEffectsResponseChunk {
+    #[prost(oneof = "RawWaitForEffectsChunk", tags = "1, 2")]
+    pub inner: Option<RawWaitForEffectsChunk>,
+}
+
+#[derive(Clone, prost::Oneof)]
+pub enum RawWaitForEffectsChunk {
+    #[prost(message, tag = "1")]
+    EffectsChunk(RawWaitForEffectsResponse),
+    #[prost(message, tag = "2")]
+    ObjectChunk(RawObjectChunk),
+}
+
+#[derive(Clone, prost::Message)]
+pub struct RawObjectChunk {
+    #[prost(bytes = "bytes", repeated, tag = "1")]
+    pub input_objects: Vec<Bytes>,
+    #[prost(bytes = "bytes", repeated, tag = "2")]
+    pub output_objects: Vec<Bytes>,
 }
 
 #[derive(Clone, prost::Message)]
@@ -467,6 +512,8 @@ pub struct RawRejectedStatus {
     pub reason: i32,
     #[prost(string, optional, tag = "2")]
     pub message: Option<String>, // Only for string-carrying variants
+    #[prost(enumeration = "RawConsensusRejectionCode", optional, tag = "3")]
+    pub consensus_code: Option<i32>, // Only when rejected by consensus
 }
 
 #[derive(Clone, Debug, prost::Enumeration)]
@@ -484,6 +531,15 @@ pub enum RawRejectReason {
     CoinDenyList = 4,
 }
 
+#[derive(Clone, Debug, prost::Enumeration)]
+#[repr(i32)]
+pub enum RawConsensusRejectionCode {
+    // Rejected by a quorum of validators when the block is certified on fastpath.
+    FastPath = 0,
+    // Rejected when the consensus commit containing the transaction is processed.
+    PostCommit = 1,
+}
+
 impl From<HandleCertificateResponseV3> for HandleCertificateResponseV2 {
     fn from(value: HandleCertificateResponseV3) -> Self {
         Self {